@@ -0,0 +1,237 @@
+//! Circuit breaker for the CYNIC API connection
+//!
+//! Tracks consecutive request failures and, once a threshold is crossed,
+//! opens to short-circuit further requests for a cooldown window rather than
+//! hammering an already-struggling upstream. After the cooldown it moves to
+//! half-open and lets exactly one trial request through to probe recovery.
+
+use parking_lot::Mutex;
+use std::time::{Duration, Instant};
+
+/// Circuit breaker state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BreakerState {
+    /// Requests flow through to the network normally
+    Closed,
+    /// Requests are short-circuited without touching the network until the
+    /// cooldown window elapses
+    Open,
+    /// Cooldown elapsed; a single trial request is allowed through to decide
+    /// whether to close or re-open
+    HalfOpen,
+}
+
+/// Snapshot of the breaker's health, for operators to see when CYNIC is
+/// being bypassed
+#[derive(Debug, Clone, Copy)]
+pub struct BreakerHealth {
+    /// Current breaker state
+    pub state: BreakerState,
+    /// Consecutive failures observed since the breaker last closed
+    pub consecutive_failures: u32,
+    /// Total retry attempts made since client creation
+    pub total_retries: u64,
+    /// Total times the breaker has tripped open since client creation
+    pub total_trips: u64,
+}
+
+/// Retry and circuit-breaker tuning for outbound CYNIC requests
+#[derive(Debug, Clone, Copy)]
+pub struct ResilienceConfig {
+    /// Consecutive failures before the breaker opens
+    pub breaker_failure_threshold: u32,
+    /// How long the breaker stays open before probing recovery
+    pub breaker_cooldown: Duration,
+    /// Maximum attempts (including the first) for a single request
+    pub retry_max_attempts: u32,
+    /// Base delay for exponential backoff between retries
+    pub retry_base_delay: Duration,
+    /// Ceiling on the backoff delay, however large the exponential term grows
+    pub retry_max_delay: Duration,
+}
+
+impl Default for ResilienceConfig {
+    fn default() -> Self {
+        Self {
+            breaker_failure_threshold: 5,
+            breaker_cooldown: Duration::from_secs(30),
+            retry_max_attempts: 3,
+            retry_base_delay: Duration::from_millis(100),
+            retry_max_delay: Duration::from_secs(2),
+        }
+    }
+}
+
+struct Inner {
+    state: BreakerState,
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    /// Whether the single half-open trial request has already been handed
+    /// out, so concurrent callers during a probe still short-circuit
+    probing: bool,
+    total_retries: u64,
+    total_trips: u64,
+}
+
+/// Per-client circuit breaker guarding outbound CYNIC API requests
+pub struct CircuitBreaker {
+    inner: Mutex<Inner>,
+    failure_threshold: u32,
+    cooldown: Duration,
+}
+
+impl CircuitBreaker {
+    /// Create a breaker that opens after `failure_threshold` consecutive
+    /// failures and stays open for `cooldown` before probing recovery
+    pub fn new(failure_threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            inner: Mutex::new(Inner {
+                state: BreakerState::Closed,
+                consecutive_failures: 0,
+                opened_at: None,
+                probing: false,
+                total_retries: 0,
+                total_trips: 0,
+            }),
+            failure_threshold,
+            cooldown,
+        }
+    }
+
+    /// Whether a request should proceed to the network right now. Returns
+    /// `false` if the breaker is open and still within its cooldown (the
+    /// caller should short-circuit to a default result instead).
+    pub fn allow_request(&self) -> bool {
+        let mut inner = self.inner.lock();
+        match inner.state {
+            BreakerState::Closed => true,
+            BreakerState::Open => {
+                let cooled_down = inner
+                    .opened_at
+                    .map(|t| t.elapsed() >= self.cooldown)
+                    .unwrap_or(false);
+                if cooled_down {
+                    inner.state = BreakerState::HalfOpen;
+                    inner.probing = true;
+                    true
+                } else {
+                    false
+                }
+            }
+            BreakerState::HalfOpen => {
+                if inner.probing {
+                    false
+                } else {
+                    inner.probing = true;
+                    true
+                }
+            }
+        }
+    }
+
+    /// Record a completed request that ultimately succeeded, closing the
+    /// breaker and resetting the consecutive failure count.
+    pub fn record_success(&self) {
+        let mut inner = self.inner.lock();
+        inner.state = BreakerState::Closed;
+        inner.consecutive_failures = 0;
+        inner.opened_at = None;
+        inner.probing = false;
+    }
+
+    /// Record a completed request that ultimately failed (after exhausting
+    /// retries). A failed half-open probe re-opens the breaker immediately;
+    /// otherwise the failure counts toward `failure_threshold`.
+    pub fn record_failure(&self) {
+        let mut inner = self.inner.lock();
+        inner.probing = false;
+        match inner.state {
+            BreakerState::HalfOpen => {
+                inner.state = BreakerState::Open;
+                inner.opened_at = Some(Instant::now());
+                inner.total_trips += 1;
+            }
+            _ => {
+                inner.consecutive_failures += 1;
+                if inner.consecutive_failures >= self.failure_threshold {
+                    inner.state = BreakerState::Open;
+                    inner.opened_at = Some(Instant::now());
+                    inner.total_trips += 1;
+                }
+            }
+        }
+    }
+
+    /// Record that a retry attempt was made, for `health()` reporting
+    pub fn record_retry(&self) {
+        self.inner.lock().total_retries += 1;
+    }
+
+    /// Snapshot of the breaker's current health
+    pub fn health(&self) -> BreakerHealth {
+        let inner = self.inner.lock();
+        BreakerHealth {
+            state: inner.state,
+            consecutive_failures: inner.consecutive_failures,
+            total_retries: inner.total_retries,
+            total_trips: inner.total_trips,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closed_allows_requests() {
+        let breaker = CircuitBreaker::new(3, Duration::from_secs(30));
+        assert!(breaker.allow_request());
+        assert_eq!(breaker.health().state, BreakerState::Closed);
+    }
+
+    #[test]
+    fn test_opens_after_threshold_failures() {
+        let breaker = CircuitBreaker::new(2, Duration::from_secs(30));
+        breaker.record_failure();
+        assert_eq!(breaker.health().state, BreakerState::Closed);
+        breaker.record_failure();
+        assert_eq!(breaker.health().state, BreakerState::Open);
+        assert!(!breaker.allow_request());
+        assert_eq!(breaker.health().total_trips, 1);
+    }
+
+    #[test]
+    fn test_success_resets_breaker() {
+        let breaker = CircuitBreaker::new(2, Duration::from_secs(30));
+        breaker.record_failure();
+        breaker.record_success();
+        assert_eq!(breaker.health().consecutive_failures, 0);
+        assert_eq!(breaker.health().state, BreakerState::Closed);
+    }
+
+    #[test]
+    fn test_half_open_probe_is_exclusive() {
+        let breaker = CircuitBreaker::new(1, Duration::from_millis(0));
+        breaker.record_failure();
+        assert_eq!(breaker.health().state, BreakerState::Open);
+
+        // Cooldown is zero, so the next check transitions to half-open and
+        // hands out the single trial slot.
+        assert!(breaker.allow_request());
+        assert_eq!(breaker.health().state, BreakerState::HalfOpen);
+        // A second concurrent caller is short-circuited until the probe
+        // resolves.
+        assert!(!breaker.allow_request());
+    }
+
+    #[test]
+    fn test_failed_probe_reopens_breaker() {
+        let breaker = CircuitBreaker::new(1, Duration::from_millis(0));
+        breaker.record_failure();
+        assert!(breaker.allow_request());
+        breaker.record_failure();
+        assert_eq!(breaker.health().state, BreakerState::Open);
+        assert_eq!(breaker.health().total_trips, 2);
+    }
+}
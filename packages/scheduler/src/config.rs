@@ -1,9 +1,94 @@
 //! Configuration for CYNIC Scheduler
 
-use crate::{PHI, PHI_INV};
+use crate::{CynicApiErrorCategory, PHI, PHI_INV};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
+/// What the scheduler does with a transaction when its CYNIC reputation
+/// lookup fails, for a given [`CynicApiErrorCategory`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiErrorPolicy {
+    /// Admit the transaction with `ReputationScore::default()` rather than
+    /// blocking it on a degraded CYNIC API
+    FailOpen,
+    /// Treat the transaction as dropped, the same as a GROWL verdict, rather
+    /// than admitting traffic CYNIC couldn't vet
+    FailClosed,
+    /// Admit the transaction with `ReputationScore::default()`, same as
+    /// `FailOpen`, but for categories (like `NotFound`) where a neutral score
+    /// is the legitimately correct answer rather than a degraded fallback
+    UseDefault,
+}
+
+/// Per-category `ApiErrorPolicy` overrides plus the `on_api_error` default,
+/// extracted from [`SchedulerConfig`] so a component that doesn't otherwise
+/// need the whole config (like [`crate::CynicClient`]'s batch path) can still
+/// resolve the same fail-open/fail-closed policy as
+/// [`SchedulerConfig::policy_for`], mirroring how [`crate::ResilienceConfig`]
+/// bundles just the breaker/retry fields for the same reason.
+#[derive(Debug, Clone, Copy)]
+pub struct ApiErrorPolicies {
+    pub default: ApiErrorPolicy,
+    pub unauthorized: Option<ApiErrorPolicy>,
+    pub rate_limited: Option<ApiErrorPolicy>,
+    pub not_found: Option<ApiErrorPolicy>,
+    pub timeout: Option<ApiErrorPolicy>,
+    pub transport: Option<ApiErrorPolicy>,
+    pub malformed: Option<ApiErrorPolicy>,
+}
+
+impl Default for ApiErrorPolicies {
+    /// Mirrors `SchedulerConfig::default`'s `on_api_error*` fields.
+    fn default() -> Self {
+        Self {
+            default: ApiErrorPolicy::FailOpen,
+            unauthorized: Some(ApiErrorPolicy::FailClosed),
+            rate_limited: None,
+            not_found: Some(ApiErrorPolicy::UseDefault),
+            timeout: None,
+            transport: None,
+            malformed: Some(ApiErrorPolicy::FailClosed),
+        }
+    }
+}
+
+impl ApiErrorPolicies {
+    /// Resolve the [`ApiErrorPolicy`] for `category`: its specific override
+    /// if set, falling back to `default` otherwise.
+    pub fn resolve(&self, category: CynicApiErrorCategory) -> ApiErrorPolicy {
+        let override_policy = match category {
+            CynicApiErrorCategory::Unauthorized => self.unauthorized,
+            CynicApiErrorCategory::RateLimited => self.rate_limited,
+            CynicApiErrorCategory::NotFound => self.not_found,
+            CynicApiErrorCategory::Timeout => self.timeout,
+            CynicApiErrorCategory::Transport => self.transport,
+            CynicApiErrorCategory::Malformed => self.malformed,
+        };
+        override_policy.unwrap_or(self.default)
+    }
+}
+
+/// How `CynicScheduler::get_conflict_free_batches` packs queued transactions
+/// into batches for workers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SchedulingMode {
+    /// Just the top-N by φ-priority in one flat batch (original behavior);
+    /// workers must still serialize on any conflicting account locks
+    Simple,
+    /// Pack transactions via a priority dependency graph so that no two
+    /// transactions placed in the same batch conflict on any account,
+    /// letting workers execute different batches in parallel
+    PrioGraph,
+}
+
+impl Default for SchedulingMode {
+    fn default() -> Self {
+        Self::Simple
+    }
+}
+
 /// Scheduler configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SchedulerConfig {
@@ -28,6 +113,35 @@ pub struct SchedulerConfig {
     /// Cache duration for reputation scores
     pub reputation_cache_ttl: Duration,
 
+    /// How much older than `reputation_cache_ttl` an entry may be and still
+    /// be served immediately (marked stale) while a background refresh is
+    /// triggered, instead of blocking the caller on a fresh fetch
+    pub reputation_stale_ttl: Duration,
+
+    /// Maximum number of reputation scores held in the LRU cache at once
+    pub reputation_cache_capacity: usize,
+
+    /// Maximum items per `/api/judge/batch` request when resolving a batch
+    /// of uncached reputation lookups
+    pub reputation_batch_size: usize,
+
+    /// Consecutive CYNIC request failures before the circuit breaker opens
+    /// and short-circuits further requests
+    pub breaker_failure_threshold: u32,
+
+    /// How long the circuit breaker stays open before probing recovery
+    pub breaker_cooldown: Duration,
+
+    /// Maximum attempts (including the first) for a single CYNIC request
+    /// before the failure is resolved by the `on_api_error*` policy
+    pub retry_max_attempts: u32,
+
+    /// Base delay for exponential backoff between CYNIC request retries
+    pub retry_base_delay: Duration,
+
+    /// Ceiling on the backoff delay between CYNIC request retries
+    pub retry_max_delay: Duration,
+
     /// Enable GROWL filtering (drop malicious transactions)
     pub enable_growl_filter: bool,
 
@@ -37,6 +151,18 @@ pub struct SchedulerConfig {
     /// Minimum E-Score to allow transaction (0 = no filter)
     pub min_e_score: f64,
 
+    /// Base minimum-effective-priority floor (φ-score) below which transactions
+    /// are rejected outright. Rises dynamically toward the worst in-queue
+    /// φ-score as occupancy approaches `max_queue_size`.
+    pub min_priority_floor: f64,
+
+    /// Maximum queued transactions per `fee_payer` (0 = unlimited). Prevents a
+    /// single payer from monopolizing the queue.
+    pub max_per_payer: usize,
+
+    /// Batch-packing strategy used by `get_conflict_free_batches`
+    pub scheduling_mode: SchedulingMode,
+
     /// φ multiplier for WAG transactions
     pub wag_multiplier: f64,
 
@@ -55,6 +181,41 @@ pub struct SchedulerConfig {
     /// Shared memory region name for progress tracker
     pub progress_shm: String,
 
+    /// Default policy applied to a CYNIC API failure whose category has no
+    /// more specific override below
+    pub on_api_error: ApiErrorPolicy,
+
+    /// Policy override for `CynicApiErrorCategory::Unauthorized`
+    pub on_api_error_unauthorized: Option<ApiErrorPolicy>,
+
+    /// Policy override for `CynicApiErrorCategory::RateLimited`
+    pub on_api_error_rate_limited: Option<ApiErrorPolicy>,
+
+    /// Policy override for `CynicApiErrorCategory::NotFound`
+    pub on_api_error_not_found: Option<ApiErrorPolicy>,
+
+    /// Policy override for `CynicApiErrorCategory::Timeout`
+    pub on_api_error_timeout: Option<ApiErrorPolicy>,
+
+    /// Policy override for `CynicApiErrorCategory::Transport`
+    pub on_api_error_transport: Option<ApiErrorPolicy>,
+
+    /// Policy override for `CynicApiErrorCategory::Malformed`
+    pub on_api_error_malformed: Option<ApiErrorPolicy>,
+
+    /// This instance's identifier, used as its heartbeat key when a
+    /// `CoordinationBackend` is attached via `CynicScheduler::set_coordination_backend`
+    pub instance_id: String,
+
+    /// How long this instance's heartbeat lease stays valid before a peer
+    /// that hasn't renewed it is considered dead
+    pub coordination_heartbeat_ttl: Duration,
+
+    /// Whether `CynicClient` consults the attached `CoordinationBackend` as a
+    /// read-through/write-through layer in front of its local cache. Has no
+    /// effect unless a backend is attached.
+    pub enable_shared_reputation_cache: bool,
+
     /// Log level
     pub log_level: String,
 }
@@ -69,20 +230,51 @@ impl Default for SchedulerConfig {
             num_workers: 4,
             api_timeout: Duration::from_millis(100),
             reputation_cache_ttl: Duration::from_secs(60),
+            reputation_stale_ttl: Duration::from_secs(300),
+            reputation_cache_capacity: 10_000,
+            reputation_batch_size: 100,
+            breaker_failure_threshold: 5,
+            breaker_cooldown: Duration::from_secs(30),
+            retry_max_attempts: 3,
+            retry_base_delay: Duration::from_millis(100),
+            retry_max_delay: Duration::from_secs(2),
             enable_growl_filter: true,
             enable_wag_boost: true,
             min_e_score: 0.0,
+            min_priority_floor: 0.0,
+            max_per_payer: 256,
+            scheduling_mode: SchedulingMode::default(),
             wag_multiplier: PHI,
             bark_multiplier: PHI_INV,
             tpu_to_pack_shm: "/cynic_tpu_to_pack".to_string(),
             pack_to_worker_shm_prefix: "/cynic_pack_to_worker_".to_string(),
             worker_to_pack_shm_prefix: "/cynic_worker_to_pack_".to_string(),
             progress_shm: "/cynic_progress".to_string(),
+            on_api_error: ApiErrorPolicy::FailOpen,
+            on_api_error_unauthorized: Some(ApiErrorPolicy::FailClosed),
+            on_api_error_rate_limited: None,
+            on_api_error_not_found: Some(ApiErrorPolicy::UseDefault),
+            on_api_error_timeout: None,
+            on_api_error_transport: None,
+            on_api_error_malformed: Some(ApiErrorPolicy::FailClosed),
+            instance_id: "cynic-scheduler".to_string(),
+            coordination_heartbeat_ttl: Duration::from_secs(15),
+            enable_shared_reputation_cache: true,
             log_level: "info".to_string(),
         }
     }
 }
 
+/// Parse an `ApiErrorPolicy` from a `CYNIC_ON_API_ERROR`-style env value
+fn parse_api_error_policy(value: &str) -> Option<ApiErrorPolicy> {
+    match value.to_lowercase().as_str() {
+        "fail_open" | "fail-open" => Some(ApiErrorPolicy::FailOpen),
+        "fail_closed" | "fail-closed" => Some(ApiErrorPolicy::FailClosed),
+        "use_default" | "use-default" => Some(ApiErrorPolicy::UseDefault),
+        _ => None,
+    }
+}
+
 impl SchedulerConfig {
     /// Create config from environment variables
     pub fn from_env() -> Self {
@@ -120,6 +312,48 @@ impl SchedulerConfig {
             }
         }
 
+        if let Ok(capacity) = std::env::var("CYNIC_REPUTATION_CACHE_CAPACITY") {
+            if let Ok(n) = capacity.parse() {
+                config.reputation_cache_capacity = n;
+            }
+        }
+
+        if let Ok(size) = std::env::var("CYNIC_REPUTATION_BATCH_SIZE") {
+            if let Ok(n) = size.parse() {
+                config.reputation_batch_size = n;
+            }
+        }
+
+        if let Ok(n) = std::env::var("CYNIC_BREAKER_FAILURE_THRESHOLD") {
+            if let Ok(threshold) = n.parse() {
+                config.breaker_failure_threshold = threshold;
+            }
+        }
+
+        if let Ok(ms) = std::env::var("CYNIC_BREAKER_COOLDOWN_MS") {
+            if let Ok(cooldown) = ms.parse() {
+                config.breaker_cooldown = Duration::from_millis(cooldown);
+            }
+        }
+
+        if let Ok(n) = std::env::var("CYNIC_RETRY_MAX_ATTEMPTS") {
+            if let Ok(attempts) = n.parse() {
+                config.retry_max_attempts = attempts;
+            }
+        }
+
+        if let Ok(ms) = std::env::var("CYNIC_RETRY_BASE_DELAY_MS") {
+            if let Ok(delay) = ms.parse() {
+                config.retry_base_delay = Duration::from_millis(delay);
+            }
+        }
+
+        if let Ok(ms) = std::env::var("CYNIC_RETRY_MAX_DELAY_MS") {
+            if let Ok(delay) = ms.parse() {
+                config.retry_max_delay = Duration::from_millis(delay);
+            }
+        }
+
         if let Ok(val) = std::env::var("CYNIC_ENABLE_GROWL_FILTER") {
             config.enable_growl_filter = val == "true" || val == "1";
         }
@@ -134,6 +368,45 @@ impl SchedulerConfig {
             }
         }
 
+        if let Ok(floor) = std::env::var("CYNIC_MIN_PRIORITY_FLOOR") {
+            if let Ok(f) = floor.parse() {
+                config.min_priority_floor = f;
+            }
+        }
+
+        if let Ok(max) = std::env::var("CYNIC_MAX_PER_PAYER") {
+            if let Ok(n) = max.parse() {
+                config.max_per_payer = n;
+            }
+        }
+
+        if let Ok(mode) = std::env::var("CYNIC_SCHEDULING_MODE") {
+            config.scheduling_mode = match mode.to_lowercase().as_str() {
+                "prio_graph" | "prio-graph" => SchedulingMode::PrioGraph,
+                _ => SchedulingMode::Simple,
+            };
+        }
+
+        if let Ok(policy) = std::env::var("CYNIC_ON_API_ERROR") {
+            if let Some(p) = parse_api_error_policy(&policy) {
+                config.on_api_error = p;
+            }
+        }
+
+        if let Ok(id) = std::env::var("CYNIC_INSTANCE_ID") {
+            config.instance_id = id;
+        }
+
+        if let Ok(ms) = std::env::var("CYNIC_COORDINATION_HEARTBEAT_TTL_MS") {
+            if let Ok(ttl) = ms.parse() {
+                config.coordination_heartbeat_ttl = Duration::from_millis(ttl);
+            }
+        }
+
+        if let Ok(val) = std::env::var("CYNIC_ENABLE_SHARED_REPUTATION_CACHE") {
+            config.enable_shared_reputation_cache = val == "true" || val == "1";
+        }
+
         if let Ok(level) = std::env::var("CYNIC_LOG_LEVEL") {
             config.log_level = level;
         }
@@ -141,6 +414,27 @@ impl SchedulerConfig {
         config
     }
 
+    /// Resolve the [`ApiErrorPolicy`] for `category`: its specific override if
+    /// set, falling back to `on_api_error` otherwise.
+    pub fn policy_for(&self, category: CynicApiErrorCategory) -> ApiErrorPolicy {
+        self.api_error_policies().resolve(category)
+    }
+
+    /// Bundle the `on_api_error*` fields into an [`ApiErrorPolicies`], for
+    /// handing to a component (like [`crate::CynicClient`]) that shouldn't
+    /// otherwise need the whole config.
+    pub fn api_error_policies(&self) -> ApiErrorPolicies {
+        ApiErrorPolicies {
+            default: self.on_api_error,
+            unauthorized: self.on_api_error_unauthorized,
+            rate_limited: self.on_api_error_rate_limited,
+            not_found: self.on_api_error_not_found,
+            timeout: self.on_api_error_timeout,
+            transport: self.on_api_error_transport,
+            malformed: self.on_api_error_malformed,
+        }
+    }
+
     /// Validate configuration
     pub fn validate(&self) -> crate::Result<()> {
         if self.max_queue_size == 0 {
@@ -165,6 +459,36 @@ impl SchedulerConfig {
             ));
         }
 
+        if self.min_priority_floor < 0.0 {
+            return Err(crate::SchedulerError::config(
+                "min_priority_floor must be >= 0",
+            ));
+        }
+
+        if self.reputation_cache_capacity == 0 {
+            return Err(crate::SchedulerError::config(
+                "reputation_cache_capacity must be > 0",
+            ));
+        }
+
+        if self.reputation_batch_size == 0 {
+            return Err(crate::SchedulerError::config(
+                "reputation_batch_size must be > 0",
+            ));
+        }
+
+        if self.breaker_failure_threshold == 0 {
+            return Err(crate::SchedulerError::config(
+                "breaker_failure_threshold must be > 0",
+            ));
+        }
+
+        if self.retry_max_attempts == 0 {
+            return Err(crate::SchedulerError::config(
+                "retry_max_attempts must be > 0",
+            ));
+        }
+
         Ok(())
     }
 }
@@ -187,4 +511,61 @@ mod tests {
         config.max_queue_size = 0;
         assert!(config.validate().is_err());
     }
+
+    #[test]
+    fn test_negative_priority_floor_invalid() {
+        let mut config = SchedulerConfig::default();
+        config.min_priority_floor = -1.0;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_zero_cache_capacity_invalid() {
+        let mut config = SchedulerConfig::default();
+        config.reputation_cache_capacity = 0;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_zero_batch_size_invalid() {
+        let mut config = SchedulerConfig::default();
+        config.reputation_batch_size = 0;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_zero_breaker_failure_threshold_invalid() {
+        let mut config = SchedulerConfig::default();
+        config.breaker_failure_threshold = 0;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_zero_retry_max_attempts_invalid() {
+        let mut config = SchedulerConfig::default();
+        config.retry_max_attempts = 0;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_policy_for_uses_category_override() {
+        let config = SchedulerConfig::default();
+        assert_eq!(
+            config.policy_for(CynicApiErrorCategory::Unauthorized),
+            ApiErrorPolicy::FailClosed
+        );
+        assert_eq!(
+            config.policy_for(CynicApiErrorCategory::NotFound),
+            ApiErrorPolicy::UseDefault
+        );
+    }
+
+    #[test]
+    fn test_policy_for_falls_back_to_default() {
+        let config = SchedulerConfig::default();
+        assert_eq!(
+            config.policy_for(CynicApiErrorCategory::Transport),
+            config.on_api_error
+        );
+    }
 }
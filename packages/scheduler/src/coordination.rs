@@ -0,0 +1,226 @@
+//! Multi-instance coordination backend
+//!
+//! A validator may run several CYNIC scheduler processes (or a hot standby
+//! waiting to take over on leader rotation). Left alone, each instance keeps
+//! an isolated in-process reputation cache and has no awareness of its
+//! peers, which means redundant CYNIC API calls and a cold cache on
+//! failover. [`CoordinationBackend`] is the extension point for an external
+//! etcd/Redis-style KV store that fixes both: `CynicClient` uses it as a
+//! read-through/write-through layer in front of its local LRU cache, and
+//! `CynicScheduler` uses it to publish this instance's heartbeat lease and
+//! observe its peers'. [`InMemoryCoordinationBackend`] implements the same
+//! trait in-process, for tests and single-instance deployments that don't
+//! need an external store.
+
+use crate::{ReputationScore, Result};
+use futures::future::BoxFuture;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// A peer's last-reported liveness, from its most recent heartbeat
+#[derive(Debug, Clone)]
+pub struct PeerLease {
+    /// The peer's `SchedulerConfig::instance_id`
+    pub instance_id: String,
+    /// Whether the peer believed it was leader as of its last heartbeat
+    pub is_leader: bool,
+    /// Time remaining on the peer's lease, as of when `live_peers` was called
+    pub remaining: Duration,
+}
+
+/// Pluggable external store backing multi-instance coordination.
+///
+/// Implementations must be cheap to share: `CynicScheduler` and
+/// `CynicClient` both hold an `Arc` to the same backend, so internal state
+/// should live behind that `Arc` rather than requiring `&mut self`.
+pub trait CoordinationBackend: Send + Sync {
+    /// Register/refresh this instance's heartbeat, valid for `ttl` from now
+    fn heartbeat(&self, instance_id: &str, is_leader: bool, ttl: Duration) -> BoxFuture<'_, Result<()>>;
+
+    /// Peers with an unexpired heartbeat lease, including this instance's own
+    /// last heartbeat if still live
+    fn live_peers(&self) -> BoxFuture<'_, Result<Vec<PeerLease>>>;
+
+    /// Read `key`'s reputation score from the shared cache, if present and
+    /// unexpired
+    fn get_reputation(&self, key: &str) -> BoxFuture<'_, Result<Option<ReputationScore>>>;
+
+    /// Write `key`'s reputation score to the shared cache, valid for `ttl`
+    fn put_reputation(
+        &self,
+        key: &str,
+        score: ReputationScore,
+        ttl: Duration,
+    ) -> BoxFuture<'_, Result<()>>;
+}
+
+struct HeartbeatEntry {
+    is_leader: bool,
+    expires_at: Instant,
+}
+
+struct ReputationEntry {
+    score: ReputationScore,
+    expires_at: Instant,
+}
+
+/// A simple in-process [`CoordinationBackend`], useful for tests and for
+/// single-instance deployments that don't need an external store. State is
+/// held in memory and is not shared across OS processes.
+#[derive(Default)]
+pub struct InMemoryCoordinationBackend {
+    heartbeats: Mutex<HashMap<String, HeartbeatEntry>>,
+    reputations: Mutex<HashMap<String, ReputationEntry>>,
+}
+
+impl InMemoryCoordinationBackend {
+    /// Create an empty backend
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CoordinationBackend for InMemoryCoordinationBackend {
+    fn heartbeat(
+        &self,
+        instance_id: &str,
+        is_leader: bool,
+        ttl: Duration,
+    ) -> BoxFuture<'_, Result<()>> {
+        let instance_id = instance_id.to_string();
+        Box::pin(async move {
+            self.heartbeats.lock().insert(
+                instance_id,
+                HeartbeatEntry {
+                    is_leader,
+                    expires_at: Instant::now() + ttl,
+                },
+            );
+            Ok(())
+        })
+    }
+
+    fn live_peers(&self) -> BoxFuture<'_, Result<Vec<PeerLease>>> {
+        Box::pin(async move {
+            let now = Instant::now();
+            let peers = self
+                .heartbeats
+                .lock()
+                .iter()
+                .filter(|(_, entry)| entry.expires_at > now)
+                .map(|(instance_id, entry)| PeerLease {
+                    instance_id: instance_id.clone(),
+                    is_leader: entry.is_leader,
+                    remaining: entry.expires_at.saturating_duration_since(now),
+                })
+                .collect();
+            Ok(peers)
+        })
+    }
+
+    fn get_reputation(&self, key: &str) -> BoxFuture<'_, Result<Option<ReputationScore>>> {
+        let key = key.to_string();
+        Box::pin(async move {
+            let reputations = self.reputations.lock();
+            let now = Instant::now();
+            Ok(reputations
+                .get(&key)
+                .filter(|entry| entry.expires_at > now)
+                .map(|entry| entry.score.clone()))
+        })
+    }
+
+    fn put_reputation(
+        &self,
+        key: &str,
+        score: ReputationScore,
+        ttl: Duration,
+    ) -> BoxFuture<'_, Result<()>> {
+        let key = key.to_string();
+        Box::pin(async move {
+            self.reputations.lock().insert(
+                key,
+                ReputationEntry {
+                    score,
+                    expires_at: Instant::now() + ttl,
+                },
+            );
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Verdict;
+
+    #[tokio::test]
+    async fn test_heartbeat_reports_as_live_peer() {
+        let backend = InMemoryCoordinationBackend::new();
+        backend
+            .heartbeat("instance-a", true, Duration::from_secs(10))
+            .await
+            .unwrap();
+
+        let peers = backend.live_peers().await.unwrap();
+        assert_eq!(peers.len(), 1);
+        assert_eq!(peers[0].instance_id, "instance-a");
+        assert!(peers[0].is_leader);
+    }
+
+    #[tokio::test]
+    async fn test_expired_heartbeat_excluded_from_live_peers() {
+        let backend = InMemoryCoordinationBackend::new();
+        backend
+            .heartbeat("instance-a", false, Duration::from_millis(0))
+            .await
+            .unwrap();
+
+        tokio::time::sleep(Duration::from_millis(5)).await;
+
+        let peers = backend.live_peers().await.unwrap();
+        assert!(peers.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reputation_write_through_then_read_through() {
+        let backend = InMemoryCoordinationBackend::new();
+        let score = ReputationScore {
+            verdict: Verdict::Wag,
+            ..ReputationScore::default()
+        };
+
+        assert!(backend
+            .get_reputation("wallet1")
+            .await
+            .unwrap()
+            .is_none());
+
+        backend
+            .put_reputation("wallet1", score.clone(), Duration::from_secs(10))
+            .await
+            .unwrap();
+
+        let fetched = backend.get_reputation("wallet1").await.unwrap().unwrap();
+        assert_eq!(fetched.verdict, Verdict::Wag);
+    }
+
+    #[tokio::test]
+    async fn test_expired_reputation_entry_is_a_miss() {
+        let backend = InMemoryCoordinationBackend::new();
+        backend
+            .put_reputation("wallet1", ReputationScore::default(), Duration::from_millis(0))
+            .await
+            .unwrap();
+
+        tokio::time::sleep(Duration::from_millis(5)).await;
+
+        assert!(backend
+            .get_reputation("wallet1")
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
@@ -1,12 +1,19 @@
 //! CYNIC API client for reputation lookups
 
-use crate::{ReputationScore, Result, SchedulerError, Verdict, PHI_INV};
-use parking_lot::RwLock;
+use crate::circuit_breaker::CircuitBreaker;
+use crate::coordination::CoordinationBackend;
+use crate::lru_cache::LruCache;
+use crate::{
+    ApiErrorPolicies, ApiErrorPolicy, BreakerHealth, CynicApiErrorCategory, ReputationScore,
+    ResilienceConfig, Result, SchedulerError, Verdict, PHI_INV,
+};
+use futures::future::{BoxFuture, FutureExt, Shared};
+use parking_lot::{Mutex, RwLock};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tracing::{debug, warn};
 
 /// Cached reputation entry
@@ -15,13 +22,66 @@ struct CacheEntry {
     cached_at: Instant,
 }
 
+/// Result of a cache lookup, distinguishing a fresh hit from one old enough
+/// to need a background refresh but still young enough to serve immediately.
+enum CacheLookup {
+    Fresh(ReputationScore),
+    Stale(ReputationScore),
+    Miss,
+}
+
+/// A reputation fetch shared by every caller that asked for the same key
+/// while it was in flight. The error is reduced to its category and a
+/// stringified message so the future's output is `Clone` (required by
+/// `Shared`); each waiter rebuilds a `SchedulerError` from its own copy.
+type InFlightFetch =
+    Shared<BoxFuture<'static, std::result::Result<ReputationScore, (CynicApiErrorCategory, String)>>>;
+
 /// CYNIC API client with caching
 pub struct CynicClient {
     client: Client,
     base_url: String,
     api_key: Option<String>,
-    cache: Arc<RwLock<HashMap<String, CacheEntry>>>,
+    cache: Arc<Mutex<LruCache<String, CacheEntry>>>,
     cache_ttl: Duration,
+    /// How much older than `cache_ttl` an entry may be and still be served
+    /// immediately (as stale) while a background refresh is triggered
+    stale_ttl: Duration,
+    /// Registry of in-flight reputation fetches, keyed by cache key, so
+    /// concurrent lookups for the same wallet/token collapse into a single
+    /// outbound HTTP request instead of each firing its own.
+    in_flight: Arc<Mutex<HashMap<String, InFlightFetch>>>,
+    /// Maximum items per `/api/judge/batch` request when resolving a batch
+    /// of uncached lookups
+    batch_size: usize,
+    /// Circuit breaker short-circuiting requests to CYNIC after repeated
+    /// failures
+    breaker: Arc<CircuitBreaker>,
+    /// Retry and circuit-breaker tuning for outbound requests
+    resilience: ResilienceConfig,
+    /// Fail-open/fail-closed policy for classified API errors, applied to
+    /// the batch path the same way `CynicScheduler::get_reputation` applies
+    /// it to the unbatched one
+    error_policies: ApiErrorPolicies,
+    /// Whether to consult/populate the coordination backend's shared
+    /// reputation cache, if one is attached
+    share_reputation_cache: bool,
+    /// Multi-instance coordination backend, if attached via
+    /// `set_coordination_backend`, consulted as a read-through/write-through
+    /// layer in front of the local cache
+    coordination: Arc<RwLock<Option<Arc<dyn CoordinationBackend>>>>,
+}
+
+/// Snapshot of `CynicClient`'s reputation cache state
+#[derive(Debug, Clone, Copy)]
+pub struct CacheStats {
+    /// Entries currently cached that haven't exceeded their TTL
+    pub valid: usize,
+    /// Total entries currently cached (including stale-but-not-yet-evicted)
+    pub total: usize,
+    /// Total LRU evictions performed since client creation (high-water mark
+    /// for capacity pressure)
+    pub evictions: u64,
 }
 
 /// CYNIC judgment request
@@ -34,14 +94,58 @@ struct JudgeRequest {
 /// CYNIC judgment response
 #[derive(Debug, Deserialize)]
 struct JudgeResponse {
-    #[serde(rename = "qScore")]
+    #[serde(rename = "qScore", default)]
     q_score: f64,
+    #[serde(default = "default_verdict")]
     verdict: Verdict,
+    #[serde(default)]
     confidence: f64,
-    #[serde(rename = "kScore")]
+    #[serde(rename = "kScore", default)]
     k_score: Option<f64>,
-    #[serde(rename = "eScore")]
+    #[serde(rename = "eScore", default)]
     e_score: Option<f64>,
+    /// Populated instead of (or alongside) the score fields when CYNIC
+    /// couldn't judge this item, even in an otherwise-200 response. A
+    /// populated error here is surfaced as `CynicApiErrorCategory::Malformed`
+    /// rather than treated as a successful judgment.
+    #[serde(default)]
+    error: Option<String>,
+}
+
+fn default_verdict() -> Verdict {
+    Verdict::Howl
+}
+
+/// CYNIC batch judgment request
+#[derive(Debug, Serialize)]
+struct BatchJudgeRequest {
+    items: Vec<JudgeRequest>,
+}
+
+/// CYNIC batch judgment response, correlated back to the request by index
+#[derive(Debug, Deserialize)]
+struct BatchJudgeResponse {
+    results: Vec<JudgeResponse>,
+}
+
+/// Exponential backoff delay for retry `attempt` (1-based), doubling from
+/// `base_delay` and capped at `max_delay`, with full jitter (uniformly
+/// randomized between 0 and the capped delay) to avoid synchronized retry
+/// storms across callers.
+fn backoff_delay(attempt: u32, base_delay: Duration, max_delay: Duration) -> Duration {
+    let shift = attempt.saturating_sub(1).min(16);
+    let scaled = base_delay.saturating_mul(1u32 << shift).min(max_delay);
+    scaled.mul_f64(jitter_fraction())
+}
+
+/// A cheap, dependency-free source of jitter in `[0.0, 1.0)`, good enough for
+/// spreading out retry timing without pulling in a full RNG crate.
+fn jitter_fraction() -> f64 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    (nanos % 1000) as f64 / 1000.0
 }
 
 impl CynicClient {
@@ -50,94 +154,372 @@ impl CynicClient {
         base_url: impl Into<String>,
         api_key: Option<String>,
         cache_ttl: Duration,
+        stale_ttl: Duration,
+        cache_capacity: usize,
+        batch_size: usize,
+        resilience: ResilienceConfig,
+        error_policies: ApiErrorPolicies,
         timeout: Duration,
+        share_reputation_cache: bool,
     ) -> Result<Self> {
-        let client = Client::builder()
-            .timeout(timeout)
-            .build()
-            .map_err(|e| SchedulerError::cynic_api(format!("Failed to create HTTP client: {}", e)))?;
+        let client = Client::builder().timeout(timeout).build().map_err(|e| {
+            SchedulerError::cynic_api(format!("Failed to create HTTP client: {}", e))
+        })?;
 
         Ok(Self {
             client,
             base_url: base_url.into(),
             api_key,
-            cache: Arc::new(RwLock::new(HashMap::new())),
+            cache: Arc::new(Mutex::new(LruCache::new(cache_capacity))),
             cache_ttl,
+            stale_ttl,
+            in_flight: Arc::new(Mutex::new(HashMap::new())),
+            batch_size,
+            breaker: Arc::new(CircuitBreaker::new(
+                resilience.breaker_failure_threshold,
+                resilience.breaker_cooldown,
+            )),
+            resilience,
+            error_policies,
+            share_reputation_cache,
+            coordination: Arc::new(RwLock::new(None)),
         })
     }
 
-    /// Get reputation score for a wallet address
-    pub async fn get_wallet_reputation(&self, address: &str) -> Result<ReputationScore> {
-        // Check cache first
-        if let Some(cached) = self.get_cached(address) {
-            debug!(address = %address, "Cache hit for wallet reputation");
-            return Ok(cached);
-        }
-
-        // Query CYNIC API
-        let score = self.query_reputation(address, "wallet").await?;
+    /// Snapshot of the circuit breaker's health, so operators can see when
+    /// CYNIC is being bypassed
+    pub fn client_health(&self) -> BreakerHealth {
+        self.breaker.health()
+    }
 
-        // Cache result
-        self.cache_score(address, score.clone());
+    /// Attach a multi-instance coordination backend, used from here on as a
+    /// read-through/write-through layer in front of the local cache
+    pub fn set_coordination_backend(&self, backend: Arc<dyn CoordinationBackend>) {
+        *self.coordination.write() = Some(backend);
+    }
 
-        Ok(score)
+    /// Get reputation score for a wallet address
+    pub async fn get_wallet_reputation(&self, address: &str) -> Result<ReputationScore> {
+        self.fetch_coalesced(address.to_string(), address.to_string(), "wallet")
+            .await
     }
 
     /// Get reputation score for a token mint
     pub async fn get_token_reputation(&self, mint: &str) -> Result<ReputationScore> {
         let cache_key = format!("token:{}", mint);
+        self.fetch_coalesced(cache_key, mint.to_string(), "token")
+            .await
+    }
+
+    /// Get a reputation score for `cache_key`, collapsing concurrent lookups
+    /// for the same key into a single outbound request: if a fetch for this
+    /// key is already in flight, await its shared result instead of issuing
+    /// a second one. An entry older than `cache_ttl` but still within
+    /// `stale_ttl` is returned immediately (marked `is_stale`) while a
+    /// refresh happens in the background.
+    async fn fetch_coalesced(
+        &self,
+        cache_key: String,
+        item: String,
+        context: &'static str,
+    ) -> Result<ReputationScore> {
+        match self.get_cached(&cache_key) {
+            CacheLookup::Fresh(score) => {
+                debug!(key = %cache_key, context = %context, "Cache hit for reputation");
+                return Ok(score);
+            }
+            CacheLookup::Stale(mut score) => {
+                debug!(key = %cache_key, context = %context, "Serving stale reputation, refreshing in background");
+                self.trigger_background_refresh(cache_key, item);
+                score.is_stale = true;
+                return Ok(score);
+            }
+            CacheLookup::Miss => {}
+        }
+
+        if let Some(score) = self.get_shared(&cache_key).await {
+            debug!(key = %cache_key, context = %context, "Shared reputation cache hit via coordination backend");
+            self.cache_score(&cache_key, score.clone());
+            return Ok(score);
+        }
+
+        let fetch = self.get_or_spawn_fetch(cache_key, item);
+        fetch
+            .await
+            .map_err(|(category, message)| SchedulerError::cynic_api_categorized(category, message))
+    }
+
+    /// Read-through: consult the attached coordination backend for `key`, if
+    /// `enable_shared_reputation_cache` allows it. Best-effort - a backend
+    /// error or miss is treated the same as a local cache miss.
+    async fn get_shared(&self, key: &str) -> Option<ReputationScore> {
+        if !self.share_reputation_cache {
+            return None;
+        }
+        let backend = self.coordination.read().clone()?;
+        match backend.get_reputation(key).await {
+            Ok(score) => score,
+            Err(e) => {
+                warn!(key = %key, error = %e, "Coordination backend reputation read failed");
+                None
+            }
+        }
+    }
 
-        // Check cache first
-        if let Some(cached) = self.get_cached(&cache_key) {
-            debug!(mint = %mint, "Cache hit for token reputation");
-            return Ok(cached);
+    /// Write-through: push `score` for `key` to the attached coordination
+    /// backend, if `enable_shared_reputation_cache` allows it. Best-effort -
+    /// a backend error only logs, it doesn't fail the fetch that produced
+    /// `score`.
+    async fn put_shared(&self, key: &str, score: &ReputationScore) {
+        if !self.share_reputation_cache {
+            return;
+        }
+        let Some(backend) = self.coordination.read().clone() else {
+            return;
+        };
+        if let Err(e) = backend
+            .put_reputation(key, score.clone(), self.cache_ttl)
+            .await
+        {
+            warn!(key = %key, error = %e, "Coordination backend reputation write failed");
         }
+    }
 
-        // Query CYNIC API
-        let score = self.query_reputation(mint, "token").await?;
+    /// Join the in-flight fetch for `cache_key`, spawning one if none exists.
+    fn get_or_spawn_fetch(&self, cache_key: String, item: String) -> InFlightFetch {
+        let mut in_flight = self.in_flight.lock();
+        match in_flight.get(&cache_key) {
+            Some(existing) => existing.clone(),
+            None => {
+                let fetch = self.spawn_fetch(cache_key.clone(), item);
+                in_flight.insert(cache_key, fetch.clone());
+                fetch
+            }
+        }
+    }
 
-        // Cache result
-        self.cache_score(&cache_key, score.clone());
+    /// Kick off a background refresh for `cache_key` if one isn't already in
+    /// flight (e.g. triggered by a concurrent stale read or blocking miss),
+    /// so a burst of stale reads for the same key only refreshes it once.
+    fn trigger_background_refresh(&self, cache_key: String, item: String) {
+        let fetch = {
+            let mut in_flight = self.in_flight.lock();
+            if in_flight.contains_key(&cache_key) {
+                return;
+            }
+            let fetch = self.spawn_fetch(cache_key.clone(), item);
+            in_flight.insert(cache_key, fetch.clone());
+            fetch
+        };
 
-        Ok(score)
+        tokio::spawn(fetch);
     }
 
-    /// Query CYNIC API for reputation
-    async fn query_reputation(&self, item: &str, context: &str) -> Result<ReputationScore> {
-        let url = format!("{}/api/judge", self.base_url);
+    /// Build the shared future that performs the HTTP call, populates the
+    /// cache on success, and removes itself from the in-flight registry
+    /// exactly once (on success or failure) when it resolves.
+    fn spawn_fetch(&self, cache_key: String, item: String) -> InFlightFetch {
+        let client = self.client.clone();
+        let base_url = self.base_url.clone();
+        let api_key = self.api_key.clone();
+        let cache = self.cache.clone();
+        let in_flight = self.in_flight.clone();
+        let breaker = self.breaker.clone();
+        let resilience = self.resilience;
+        let coordination = self.coordination.clone();
+        let share_reputation_cache = self.share_reputation_cache;
+        let cache_ttl = self.cache_ttl;
+        let context = if cache_key.starts_with("token:") {
+            "token"
+        } else {
+            "wallet"
+        };
 
-        let mut request = self.client
-            .post(&url)
-            .json(&JudgeRequest {
-                item: item.to_string(),
-                context: context.to_string(),
+        async move {
+            let result = Self::query_reputation_resilient(
+                &client,
+                &base_url,
+                api_key.as_deref(),
+                &item,
+                context,
+                &breaker,
+                &resilience,
+            )
+            .await
+            .map_err(|e| match e {
+                SchedulerError::CynicApiCategorized { category, message } => (category, message),
+                other => (CynicApiErrorCategory::Transport, other.to_string()),
             });
 
-        if let Some(ref key) = self.api_key {
+            if let Ok(ref score) = result {
+                cache.lock().insert(
+                    cache_key.clone(),
+                    CacheEntry {
+                        score: score.clone(),
+                        cached_at: Instant::now(),
+                    },
+                );
+
+                if share_reputation_cache {
+                    if let Some(backend) = coordination.read().clone() {
+                        if let Err(e) = backend
+                            .put_reputation(&cache_key, score.clone(), cache_ttl)
+                            .await
+                        {
+                            warn!(key = %cache_key, error = %e, "Coordination backend reputation write failed");
+                        }
+                    }
+                }
+            }
+
+            in_flight.lock().remove(&cache_key);
+            result
+        }
+        .boxed()
+        .shared()
+    }
+
+    /// Query CYNIC for reputation with retry-with-backoff and circuit
+    /// breaker protection. Short-circuits to a classified `Transport` error
+    /// without touching the network while the breaker is open. Only
+    /// `Transport`- and `Timeout`-category errors (network failures, 5xx,
+    /// request timeouts) are retried, up to `resilience.retry_max_attempts`
+    /// attempts in total (the first included); every other category (Unauthorized, RateLimited, NotFound,
+    /// Malformed) is terminal and returned immediately, since retrying can't
+    /// fix them. The caller decides what to do with a returned error via
+    /// `SchedulerConfig::policy_for`.
+    async fn query_reputation_resilient(
+        client: &Client,
+        base_url: &str,
+        api_key: Option<&str>,
+        item: &str,
+        context: &str,
+        breaker: &CircuitBreaker,
+        resilience: &ResilienceConfig,
+    ) -> Result<ReputationScore> {
+        if !breaker.allow_request() {
+            debug!(context = %context, "Circuit breaker open, skipping CYNIC request");
+            return Err(SchedulerError::cynic_api_categorized(
+                CynicApiErrorCategory::Transport,
+                "circuit breaker open",
+            ));
+        }
+
+        let mut attempt = 1;
+        loop {
+            match Self::query_reputation(client, base_url, api_key, item, context).await {
+                Ok(score) => {
+                    breaker.record_success();
+                    return Ok(score);
+                }
+                Err(SchedulerError::CynicApiCategorized { category, message })
+                    if (category == CynicApiErrorCategory::Transport
+                        || category == CynicApiErrorCategory::Timeout)
+                        && attempt < resilience.retry_max_attempts =>
+                {
+                    warn!(attempt, error = %message, "CYNIC request failed, retrying");
+                    breaker.record_retry();
+                    tokio::time::sleep(backoff_delay(
+                        attempt,
+                        resilience.retry_base_delay,
+                        resilience.retry_max_delay,
+                    ))
+                    .await;
+                    attempt += 1;
+                }
+                Err(SchedulerError::CynicApiCategorized { category, message }) => {
+                    if category == CynicApiErrorCategory::Transport
+                        || category == CynicApiErrorCategory::Timeout
+                    {
+                        warn!(attempts = attempt, error = %message, "CYNIC request exhausted retries");
+                        breaker.record_failure();
+                    } else {
+                        // A classified-but-terminal response (Unauthorized,
+                        // RateLimited, NotFound, Malformed) still means the
+                        // server answered, so the service is reachable - treat
+                        // it as breaker success rather than leaving a HalfOpen
+                        // probe stuck in `probing` forever.
+                        debug!(category = ?category, error = %message, "CYNIC request failed with a non-retryable error");
+                        breaker.record_success();
+                    }
+                    return Err(SchedulerError::cynic_api_categorized(category, message));
+                }
+                Err(other) => return Err(other),
+            }
+        }
+    }
+
+    /// Classify an unsuccessful HTTP status into a `CynicApiErrorCategory`
+    fn classify_status(status: reqwest::StatusCode) -> CynicApiErrorCategory {
+        match status {
+            reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN => {
+                CynicApiErrorCategory::Unauthorized
+            }
+            reqwest::StatusCode::TOO_MANY_REQUESTS => CynicApiErrorCategory::RateLimited,
+            reqwest::StatusCode::NOT_FOUND => CynicApiErrorCategory::NotFound,
+            s if s.is_server_error() => CynicApiErrorCategory::Transport,
+            // Any other 4xx: the request itself was rejected for a reason we
+            // don't have a dedicated category for - treat the response as
+            // untrustworthy rather than silently admitting it.
+            _ => CynicApiErrorCategory::Malformed,
+        }
+    }
+
+    /// Query CYNIC API for reputation, classifying every failure into a
+    /// `CynicApiErrorCategory` rather than flattening it to a default score.
+    async fn query_reputation(
+        client: &Client,
+        base_url: &str,
+        api_key: Option<&str>,
+        item: &str,
+        context: &str,
+    ) -> Result<ReputationScore> {
+        let url = format!("{}/api/judge", base_url);
+
+        let mut request = client.post(&url).json(&JudgeRequest {
+            item: item.to_string(),
+            context: context.to_string(),
+        });
+
+        if let Some(key) = api_key {
             request = request.header("X-API-Key", key);
         }
 
-        let response = request
-            .send()
-            .await
-            .map_err(|e| {
-                warn!(error = %e, "CYNIC API request failed");
-                SchedulerError::cynic_api(format!("Request failed: {}", e))
-            })?;
+        let response = request.send().await.map_err(|e| {
+            warn!(error = %e, "CYNIC API request failed");
+            let category = if e.is_timeout() {
+                CynicApiErrorCategory::Timeout
+            } else {
+                CynicApiErrorCategory::Transport
+            };
+            SchedulerError::cynic_api_categorized(category, format!("Request failed: {}", e))
+        })?;
 
-        if !response.status().is_success() {
-            let status = response.status();
+        let status = response.status();
+        if !status.is_success() {
+            let category = Self::classify_status(status);
             let body = response.text().await.unwrap_or_default();
-            warn!(status = %status, body = %body, "CYNIC API error response");
-
-            // Return default score on API error (don't block transactions)
-            return Ok(ReputationScore::default());
+            warn!(status = %status, body = %body, category = ?category, "CYNIC API error response");
+            return Err(SchedulerError::cynic_api_categorized(
+                category,
+                format!("{} {}", status, body),
+            ));
         }
 
-        let judge_response: JudgeResponse = response
-            .json()
-            .await
-            .map_err(|e| SchedulerError::cynic_api(format!("Failed to parse response: {}", e)))?;
+        let judge_response: JudgeResponse = response.json().await.map_err(|e| {
+            SchedulerError::cynic_api_categorized(
+                CynicApiErrorCategory::Malformed,
+                format!("Failed to parse response: {}", e),
+            )
+        })?;
+
+        if let Some(error) = judge_response.error.filter(|e| !e.is_empty()) {
+            warn!(error = %error, "CYNIC API returned an error in an otherwise-200 response");
+            return Err(SchedulerError::cynic_api_categorized(
+                CynicApiErrorCategory::Malformed,
+                error,
+            ));
+        }
 
         Ok(ReputationScore {
             q_score: judge_response.q_score,
@@ -145,23 +527,121 @@ impl CynicClient {
             k_score: judge_response.k_score,
             e_score: judge_response.e_score,
             confidence: judge_response.confidence.min(PHI_INV * 100.0), // Cap at 61.8%
+            is_stale: false,
         })
     }
 
-    /// Get cached score if still valid
-    fn get_cached(&self, key: &str) -> Option<ReputationScore> {
-        let cache = self.cache.read();
-        if let Some(entry) = cache.get(key) {
-            if entry.cached_at.elapsed() < self.cache_ttl {
-                return Some(entry.score.clone());
+    /// Query the CYNIC batch judgment endpoint for `items` (item, context
+    /// pairs). Returns `Ok(None)` only if the server doesn't support the
+    /// batch route (404), so the caller can fall back to the per-item path;
+    /// `Ok(Some(results))` correlated 1:1 with `items` on success, with each
+    /// entry its own `Result` so one item's error doesn't discard the scores
+    /// already parsed for the rest of the batch. A batch-wide failure - a
+    /// non-2xx/404 status or a malformed response - is classified and
+    /// propagated as the outer `Err` rather than folded into a default score
+    /// here; `resolve_batch` applies `ApiErrorPolicies::resolve` to decide
+    /// what to do with both the outer and the per-item failures.
+    async fn query_reputation_batch(
+        client: &Client,
+        base_url: &str,
+        api_key: Option<&str>,
+        items: &[(String, String)],
+    ) -> Result<Option<Vec<std::result::Result<ReputationScore, (CynicApiErrorCategory, String)>>>>
+    {
+        let url = format!("{}/api/judge/batch", base_url);
+
+        let body = BatchJudgeRequest {
+            items: items
+                .iter()
+                .map(|(item, context)| JudgeRequest {
+                    item: item.clone(),
+                    context: context.clone(),
+                })
+                .collect(),
+        };
+
+        let mut request = client.post(&url).json(&body);
+        if let Some(key) = api_key {
+            request = request.header("X-API-Key", key);
+        }
+
+        let response = request.send().await.map_err(|e| {
+            warn!(error = %e, "CYNIC batch API request failed");
+            SchedulerError::cynic_api(format!("Batch request failed: {}", e))
+        })?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let category = Self::classify_status(status);
+            let body = response.text().await.unwrap_or_default();
+            warn!(status = %status, body = %body, category = ?category, "CYNIC batch API error response");
+
+            // Classify and propagate, same as the single-item path - the
+            // caller applies `ApiErrorPolicies::resolve` rather than us
+            // deciding unilaterally to default-score the chunk.
+            return Err(SchedulerError::cynic_api_categorized(
+                category,
+                format!("{} {}", status, body),
+            ));
+        }
+
+        let batch_response: BatchJudgeResponse = response.json().await.map_err(|e| {
+            SchedulerError::cynic_api_categorized(
+                CynicApiErrorCategory::Malformed,
+                format!("Failed to parse batch response: {}", e),
+            )
+        })?;
+
+        let mut results = Vec::with_capacity(batch_response.results.len());
+        for r in batch_response.results {
+            if let Some(error) = r.error.filter(|e| !e.is_empty()) {
+                warn!(error = %error, "CYNIC batch API returned an error for a batch item");
+                results.push(Err((CynicApiErrorCategory::Malformed, error)));
+                continue;
             }
+
+            results.push(Ok(ReputationScore {
+                q_score: r.q_score,
+                verdict: r.verdict,
+                k_score: r.k_score,
+                e_score: r.e_score,
+                confidence: r.confidence.min(PHI_INV * 100.0),
+                is_stale: false,
+            }));
+        }
+
+        Ok(Some(results))
+    }
+
+    /// Look up `key`, distinguishing a fresh hit from one old enough to need
+    /// a background refresh (but still young enough to serve immediately)
+    /// from a true miss. A hit, fresh or stale, promotes the entry to
+    /// most-recently-used so the LRU eviction policy tracks actual usage
+    /// rather than just insertion order.
+    fn get_cached(&self, key: &str) -> CacheLookup {
+        let mut cache = self.cache.lock();
+        let Some(entry) = cache.get(&key.to_string()) else {
+            return CacheLookup::Miss;
+        };
+
+        let age = entry.cached_at.elapsed();
+        if age < self.cache_ttl {
+            CacheLookup::Fresh(entry.score.clone())
+        } else if age < self.stale_ttl {
+            CacheLookup::Stale(entry.score.clone())
+        } else {
+            CacheLookup::Miss
         }
-        None
     }
 
-    /// Cache a reputation score
+    /// Cache a reputation score, evicting the least-recently-used entry in
+    /// O(1) if the cache is already at capacity
     fn cache_score(&self, key: &str, score: ReputationScore) {
-        let mut cache = self.cache.write();
+        let mut cache = self.cache.lock();
         cache.insert(
             key.to_string(),
             CacheEntry {
@@ -169,65 +649,253 @@ impl CynicClient {
                 cached_at: Instant::now(),
             },
         );
-
-        // Prune old entries if cache is too large
-        if cache.len() > 10_000 {
-            let now = Instant::now();
-            cache.retain(|_, entry| now.duration_since(entry.cached_at) < self.cache_ttl);
-        }
     }
 
     /// Clear the cache
     pub fn clear_cache(&self) {
-        let mut cache = self.cache.write();
+        let mut cache = self.cache.lock();
         cache.clear();
     }
 
     /// Get cache statistics
-    pub fn cache_stats(&self) -> (usize, usize) {
-        let cache = self.cache.read();
+    pub fn cache_stats(&self) -> CacheStats {
+        let cache = self.cache.lock();
         let total = cache.len();
         let valid = cache
             .values()
             .filter(|e| e.cached_at.elapsed() < self.cache_ttl)
             .count();
-        (valid, total)
+        CacheStats {
+            valid,
+            total,
+            evictions: cache.evictions(),
+        }
     }
 }
 
 /// Batch reputation lookup for multiple addresses
 impl CynicClient {
-    /// Get reputation scores for multiple wallets (parallel)
+    /// Get reputation scores for multiple wallets via the batch endpoint. An
+    /// address may be absent from the returned map if its lookup failed with
+    /// an `ApiErrorPolicy::FailClosed` category - see `resolve_batch`.
     pub async fn get_batch_wallet_reputation(
         &self,
         addresses: &[&str],
     ) -> HashMap<String, ReputationScore> {
         let mut results = HashMap::new();
 
-        // First, collect cached results
+        // First, collect cached results (fresh or stale; stale entries still
+        // trigger their own background refresh for anything not already
+        // covered here)
         let mut uncached = Vec::new();
         for addr in addresses {
-            if let Some(cached) = self.get_cached(addr) {
-                results.insert(addr.to_string(), cached);
-            } else {
-                uncached.push(*addr);
+            match self.get_cached(addr) {
+                CacheLookup::Fresh(score) => {
+                    results.insert(addr.to_string(), score);
+                }
+                CacheLookup::Stale(mut score) => {
+                    self.trigger_background_refresh(addr.to_string(), addr.to_string());
+                    score.is_stale = true;
+                    results.insert(addr.to_string(), score);
+                }
+                CacheLookup::Miss => uncached.push(addr.to_string()),
             }
         }
 
-        // Query uncached in parallel (with limit)
-        let futures: Vec<_> = uncached
-            .iter()
-            .take(100) // Limit concurrent requests
-            .map(|addr| async move {
-                let score = self.get_wallet_reputation(addr).await.unwrap_or_default();
-                (addr.to_string(), score)
-            })
-            .collect();
+        results.extend(self.resolve_batch(uncached, "").await);
+        results
+    }
 
-        let fetched: Vec<_> = futures::future::join_all(futures).await;
+    /// Get reputation scores for multiple token mints via the batch
+    /// endpoint. A mint may be absent from the returned map if its lookup
+    /// failed with an `ApiErrorPolicy::FailClosed` category - see
+    /// `resolve_batch`.
+    pub async fn get_batch_token_reputation(
+        &self,
+        mints: &[&str],
+    ) -> HashMap<String, ReputationScore> {
+        let mut results = HashMap::new();
 
-        for (addr, score) in fetched {
-            results.insert(addr, score);
+        let mut uncached = Vec::new();
+        for mint in mints {
+            let cache_key = format!("token:{}", mint);
+            match self.get_cached(&cache_key) {
+                CacheLookup::Fresh(score) => {
+                    results.insert(mint.to_string(), score);
+                }
+                CacheLookup::Stale(mut score) => {
+                    self.trigger_background_refresh(cache_key, mint.to_string());
+                    score.is_stale = true;
+                    results.insert(mint.to_string(), score);
+                }
+                CacheLookup::Miss => uncached.push(mint.to_string()),
+            }
+        }
+
+        results.extend(self.resolve_batch(uncached, "token:").await);
+        results
+    }
+
+    /// Resolve `uncached` items (already cache-key prefix stripped) against
+    /// the batch judgment endpoint, chunked into `batch_size`-sized requests
+    /// so every item is covered rather than truncated. Falls back to the
+    /// per-item coalesced path for a chunk if the server doesn't support the
+    /// batch route. A classified failure - whether the whole batch request or
+    /// a single item within an otherwise-successful batch response - is
+    /// resolved via `error_policies`, same as `CynicScheduler::get_reputation`
+    /// does for the unbatched path: `FailOpen`/`UseDefault` admits with
+    /// `ReputationScore::default()`, `FailClosed` drops the affected id(s)
+    /// from the returned map entirely rather than silently admitting them
+    /// with a neutral score.
+    async fn resolve_batch(
+        &self,
+        uncached: Vec<String>,
+        key_prefix: &'static str,
+    ) -> HashMap<String, ReputationScore> {
+        let mut results = HashMap::new();
+        let context = if key_prefix.is_empty() {
+            "wallet"
+        } else {
+            "token"
+        };
+
+        for chunk in uncached.chunks(self.batch_size.max(1)) {
+            // Read-through: anything the coordination backend already has
+            // cached is served from there, skipping the CYNIC round trip
+            // entirely for that item.
+            let mut still_uncached = Vec::with_capacity(chunk.len());
+            for id in chunk {
+                let cache_key = format!("{}{}", key_prefix, id);
+                match self.get_shared(&cache_key).await {
+                    Some(score) => {
+                        self.cache_score(&cache_key, score.clone());
+                        results.insert(id.clone(), score);
+                    }
+                    None => still_uncached.push(id.clone()),
+                }
+            }
+
+            if still_uncached.is_empty() {
+                continue;
+            }
+
+            let items: Vec<(String, String)> = still_uncached
+                .iter()
+                .map(|id| (id.clone(), id.clone()))
+                .collect();
+
+            let batch = match Self::query_reputation_batch(
+                &self.client,
+                &self.base_url,
+                self.api_key.as_deref(),
+                &items,
+            )
+            .await
+            {
+                Ok(batch) => batch,
+                Err(SchedulerError::CynicApiCategorized { category, message }) => {
+                    match self.error_policies.resolve(category) {
+                        ApiErrorPolicy::FailOpen | ApiErrorPolicy::UseDefault => {
+                            debug!(
+                                category = ?category,
+                                error = %message,
+                                "CYNIC batch lookup failed, admitting chunk with default scores"
+                            );
+                            Some(vec![Ok(ReputationScore::default()); still_uncached.len()])
+                        }
+                        ApiErrorPolicy::FailClosed => {
+                            warn!(
+                                category = ?category,
+                                error = %message,
+                                "CYNIC batch lookup failed closed, dropping chunk from results"
+                            );
+                            continue;
+                        }
+                    }
+                }
+                Err(e) => {
+                    warn!(error = %e, "CYNIC batch lookup failed, falling back to per-item");
+                    None
+                }
+            };
+
+            match batch {
+                Some(items) => {
+                    for (id, item) in still_uncached.iter().zip(items) {
+                        let score = match item {
+                            Ok(score) => score,
+                            Err((category, message)) => match self.error_policies.resolve(category)
+                            {
+                                ApiErrorPolicy::FailOpen | ApiErrorPolicy::UseDefault => {
+                                    debug!(
+                                        id = %id,
+                                        category = ?category,
+                                        error = %message,
+                                        "CYNIC batch item failed, admitting with default score"
+                                    );
+                                    ReputationScore::default()
+                                }
+                                ApiErrorPolicy::FailClosed => {
+                                    warn!(
+                                        id = %id,
+                                        category = ?category,
+                                        error = %message,
+                                        "CYNIC batch item failed closed, dropping from results"
+                                    );
+                                    continue;
+                                }
+                            },
+                        };
+                        let cache_key = format!("{}{}", key_prefix, id);
+                        self.cache_score(&cache_key, score.clone());
+                        self.put_shared(&cache_key, &score).await;
+                        results.insert(id.clone(), score);
+                    }
+                }
+                None => {
+                    let fetched: Vec<_> =
+                        futures::future::join_all(still_uncached.iter().map(|id| async move {
+                            let cache_key = format!("{}{}", key_prefix, id);
+                            match self
+                                .fetch_coalesced(cache_key, id.clone(), context)
+                                .await
+                            {
+                                Ok(score) => Some((id.clone(), score)),
+                                Err(SchedulerError::CynicApiCategorized { category, message }) => {
+                                    match self.error_policies.resolve(category) {
+                                        ApiErrorPolicy::FailOpen | ApiErrorPolicy::UseDefault => {
+                                            debug!(
+                                                id = %id,
+                                                category = ?category,
+                                                error = %message,
+                                                "CYNIC per-item lookup failed, admitting with default score"
+                                            );
+                                            Some((id.clone(), ReputationScore::default()))
+                                        }
+                                        ApiErrorPolicy::FailClosed => {
+                                            warn!(
+                                                id = %id,
+                                                category = ?category,
+                                                error = %message,
+                                                "CYNIC per-item lookup failed closed, dropping from results"
+                                            );
+                                            None
+                                        }
+                                    }
+                                }
+                                Err(e) => {
+                                    warn!(id = %id, error = %e, "Failed to get reputation, using default");
+                                    Some((id.clone(), ReputationScore::default()))
+                                }
+                            }
+                        }))
+                        .await;
+
+                    for (id, score) in fetched.into_iter().flatten() {
+                        results.insert(id, score);
+                    }
+                }
+            }
         }
 
         results
@@ -246,14 +914,119 @@ mod tests {
         assert!((score.confidence - 61.8).abs() < 0.1);
     }
 
+    #[test]
+    fn test_classify_status() {
+        assert_eq!(
+            CynicClient::classify_status(reqwest::StatusCode::UNAUTHORIZED),
+            CynicApiErrorCategory::Unauthorized
+        );
+        assert_eq!(
+            CynicClient::classify_status(reqwest::StatusCode::FORBIDDEN),
+            CynicApiErrorCategory::Unauthorized
+        );
+        assert_eq!(
+            CynicClient::classify_status(reqwest::StatusCode::TOO_MANY_REQUESTS),
+            CynicApiErrorCategory::RateLimited
+        );
+        assert_eq!(
+            CynicClient::classify_status(reqwest::StatusCode::NOT_FOUND),
+            CynicApiErrorCategory::NotFound
+        );
+        assert_eq!(
+            CynicClient::classify_status(reqwest::StatusCode::BAD_GATEWAY),
+            CynicApiErrorCategory::Transport
+        );
+        assert_eq!(
+            CynicClient::classify_status(reqwest::StatusCode::BAD_REQUEST),
+            CynicApiErrorCategory::Malformed
+        );
+    }
+
+    #[test]
+    fn test_judge_response_error_field_not_treated_as_success() {
+        let body = r#"{"error": "unknown wallet format"}"#;
+        let parsed: JudgeResponse = serde_json::from_str(body).unwrap();
+        assert_eq!(parsed.error.as_deref(), Some("unknown wallet format"));
+    }
+
     #[tokio::test]
     async fn test_client_creation() {
         let client = CynicClient::new(
             "https://example.com",
             None,
             Duration::from_secs(60),
+            Duration::from_secs(300),
+            10_000,
+            100,
+            ResilienceConfig::default(),
+            ApiErrorPolicies::default(),
             Duration::from_millis(100),
+            true,
         );
         assert!(client.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_shared_cache_read_through_avoids_network() {
+        let client = CynicClient::new(
+            "https://example.invalid",
+            None,
+            Duration::from_secs(60),
+            Duration::from_secs(300),
+            10,
+            100,
+            ResilienceConfig::default(),
+            ApiErrorPolicies::default(),
+            Duration::from_millis(50),
+            true,
+        )
+        .unwrap();
+
+        let backend = Arc::new(crate::coordination::InMemoryCoordinationBackend::new());
+        backend
+            .put_reputation(
+                "wallet1",
+                ReputationScore {
+                    verdict: Verdict::Wag,
+                    ..ReputationScore::default()
+                },
+                Duration::from_secs(60),
+            )
+            .await
+            .unwrap();
+        client.set_coordination_backend(backend);
+
+        // Served from the shared cache; never touches the (invalid) network.
+        let score = client.get_wallet_reputation("wallet1").await.unwrap();
+        assert_eq!(score.verdict, Verdict::Wag);
+    }
+
+    #[tokio::test]
+    async fn test_cache_evicts_least_recently_used() {
+        let client = CynicClient::new(
+            "https://example.com",
+            None,
+            Duration::from_secs(60),
+            Duration::from_secs(300),
+            2,
+            100,
+            ResilienceConfig::default(),
+            ApiErrorPolicies::default(),
+            Duration::from_millis(100),
+            true,
+        )
+        .unwrap();
+
+        client.cache_score("a", ReputationScore::default());
+        client.cache_score("b", ReputationScore::default());
+        // Touch "a" so it's no longer the least-recently-used.
+        assert!(matches!(client.get_cached("a"), CacheLookup::Fresh(_)));
+
+        client.cache_score("c", ReputationScore::default());
+
+        assert!(matches!(client.get_cached("b"), CacheLookup::Miss));
+        assert!(matches!(client.get_cached("a"), CacheLookup::Fresh(_)));
+        assert!(matches!(client.get_cached("c"), CacheLookup::Fresh(_)));
+        assert_eq!(client.cache_stats().evictions, 1);
+    }
 }
@@ -5,6 +5,30 @@ use thiserror::Error;
 /// Result type for scheduler operations
 pub type Result<T> = std::result::Result<T, SchedulerError>;
 
+/// Classification of a CYNIC API failure, used to pick a fail-open vs
+/// fail-closed policy (see `SchedulerConfig::policy_for`) and tracked
+/// per-category in `SchedulerStats` so operators can see when CYNIC is
+/// degraded and in what way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CynicApiErrorCategory {
+    /// Missing, invalid, or insufficient API key (401/403)
+    Unauthorized,
+    /// Rate limited by the CYNIC API (429)
+    RateLimited,
+    /// The queried address/mint (or endpoint) is unknown to CYNIC (404)
+    NotFound,
+    /// The request exceeded `api_timeout`
+    Timeout,
+    /// Connection failure, or another transport-level problem (including
+    /// exhausted retries on a 5xx response)
+    Transport,
+    /// The response body failed to parse, carried a populated `error` field
+    /// in an otherwise-200 response, or was rejected with a 4xx status that
+    /// has no more specific category
+    Malformed,
+}
+
 /// Scheduler errors
 #[derive(Error, Debug)]
 pub enum SchedulerError {
@@ -16,6 +40,15 @@ pub enum SchedulerError {
     #[error("CYNIC API error: {0}")]
     CynicApi(String),
 
+    /// CYNIC API error, classified for fail-open/fail-closed policy decisions
+    #[error("CYNIC API error ({category:?}): {message}")]
+    CynicApiCategorized {
+        /// Failure category
+        category: CynicApiErrorCategory,
+        /// Human-readable detail
+        message: String,
+    },
+
     /// Network error
     #[error("Network error: {0}")]
     Network(#[from] reqwest::Error),
@@ -32,6 +65,10 @@ pub enum SchedulerError {
     #[error("Queue error: {0}")]
     Queue(String),
 
+    /// Transaction priority below the effective minimum floor
+    #[error("Priority below minimum floor: {0}")]
+    BelowMinPriority(String),
+
     /// Shared memory error
     #[error("Shared memory error: {0}")]
     SharedMemory(String),
@@ -60,6 +97,14 @@ impl SchedulerError {
         Self::CynicApi(msg.into())
     }
 
+    /// Create a classified CYNIC API error
+    pub fn cynic_api_categorized(category: CynicApiErrorCategory, msg: impl Into<String>) -> Self {
+        Self::CynicApiCategorized {
+            category,
+            message: msg.into(),
+        }
+    }
+
     /// Create a transaction parse error
     pub fn tx_parse(msg: impl Into<String>) -> Self {
         Self::TransactionParse(msg.into())
@@ -70,6 +115,11 @@ impl SchedulerError {
         Self::Queue(msg.into())
     }
 
+    /// Create a below-minimum-priority error
+    pub fn below_min_priority(msg: impl Into<String>) -> Self {
+        Self::BelowMinPriority(msg.into())
+    }
+
     /// Create a shared memory error
     pub fn shared_memory(msg: impl Into<String>) -> Self {
         Self::SharedMemory(msg.into())
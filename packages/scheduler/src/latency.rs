@@ -0,0 +1,365 @@
+//! Scoped latency instrumentation for hot queue paths
+//!
+//! Gated behind the `latency-metrics` feature so the recording overhead
+//! (an `Instant::now()` and a histogram update per call: mutex-guarded for
+//! `LatencyHistogram`, lock-free atomics for `BucketHistogram`)
+//! compiles out entirely in latency-critical builds that don't need it.
+
+#![cfg(feature = "latency-metrics")]
+
+use parking_lot::Mutex;
+use std::time::Instant;
+
+/// Number of recent samples retained for percentile estimation. Bounded so a
+/// long-running process doesn't grow the histogram without limit; old
+/// samples are overwritten round-robin once the ring fills.
+const SAMPLE_CAPACITY: usize = 1024;
+
+#[derive(Debug, Default)]
+struct HistogramInner {
+    count: u64,
+    sum_ns: u64,
+    min_ns: u64,
+    max_ns: u64,
+    samples: Vec<u64>,
+    next_slot: usize,
+}
+
+/// Min/max/mean/p99 latency histogram for a single instrumented path.
+///
+/// Recording is O(1); `snapshot` sorts the bounded sample window to derive
+/// p99, which is O(`SAMPLE_CAPACITY` log `SAMPLE_CAPACITY`) and only runs
+/// when stats are actually read.
+#[derive(Debug, Default)]
+pub struct LatencyHistogram {
+    inner: Mutex<HistogramInner>,
+}
+
+impl LatencyHistogram {
+    /// Create an empty histogram
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record one observed duration, in nanoseconds
+    pub fn record(&self, duration_ns: u64) {
+        let mut inner = self.inner.lock();
+        inner.min_ns = if inner.count == 0 {
+            duration_ns
+        } else {
+            inner.min_ns.min(duration_ns)
+        };
+        inner.max_ns = inner.max_ns.max(duration_ns);
+        inner.count += 1;
+        inner.sum_ns += duration_ns;
+
+        let slot = inner.next_slot;
+        if slot < inner.samples.len() {
+            inner.samples[slot] = duration_ns;
+        } else {
+            inner.samples.push(duration_ns);
+        }
+        inner.next_slot = (slot + 1) % SAMPLE_CAPACITY;
+    }
+
+    /// Point-in-time snapshot of min/max/mean/p99
+    pub fn snapshot(&self) -> LatencyStats {
+        let inner = self.inner.lock();
+        if inner.count == 0 {
+            return LatencyStats::default();
+        }
+
+        let mut sorted = inner.samples.clone();
+        sorted.sort_unstable();
+        let p99_idx = (((sorted.len() as f64) * 0.99).ceil() as usize)
+            .saturating_sub(1)
+            .min(sorted.len() - 1);
+
+        LatencyStats {
+            count: inner.count,
+            min_ns: inner.min_ns,
+            max_ns: inner.max_ns,
+            mean_ns: inner.sum_ns / inner.count,
+            p99_ns: sorted[p99_idx],
+        }
+    }
+
+    /// Discard all recorded samples, for windowed sampling
+    pub fn reset(&self) {
+        *self.inner.lock() = HistogramInner::default();
+    }
+}
+
+/// Point-in-time snapshot of a [`LatencyHistogram`]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LatencyStats {
+    /// Number of samples the histogram has seen since the last reset
+    pub count: u64,
+    /// Fastest observed duration, in nanoseconds
+    pub min_ns: u64,
+    /// Slowest observed duration, in nanoseconds
+    pub max_ns: u64,
+    /// Arithmetic mean duration, in nanoseconds
+    pub mean_ns: u64,
+    /// 99th percentile duration over the retained sample window, in nanoseconds
+    pub p99_ns: u64,
+}
+
+/// RAII guard that records its own lifetime into a [`LatencyHistogram`] on `Drop`.
+///
+/// ```ignore
+/// let _timer = ScopedTimer::new(&histogram);
+/// // ... timed work ...
+/// // duration recorded when `_timer` goes out of scope
+/// ```
+pub struct ScopedTimer<'a> {
+    start: Instant,
+    histogram: &'a LatencyHistogram,
+}
+
+impl<'a> ScopedTimer<'a> {
+    /// Start timing, recording into `histogram` when the guard is dropped
+    pub fn new(histogram: &'a LatencyHistogram) -> Self {
+        Self {
+            start: Instant::now(),
+            histogram,
+        }
+    }
+}
+
+impl Drop for ScopedTimer<'_> {
+    fn drop(&mut self) {
+        self.histogram
+            .record(self.start.elapsed().as_nanos() as u64);
+    }
+}
+
+/// Number of log2 buckets in a [`BucketHistogram`], covering durations up to
+/// roughly 2^47 microseconds (~4.5 years) - far beyond anything this
+/// scheduler would ever observe, but cheap to size generously up front.
+const NUM_BUCKETS: usize = 48;
+
+/// Point-in-time p50/p90/p99/max snapshot of a [`BucketHistogram`], in
+/// microseconds. Percentiles are the upper bound of whichever power-of-two
+/// bucket they fall in, not exact - acceptable for the tail-latency signal
+/// this exists to give operators.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LatencyQuantiles {
+    /// Number of samples recorded since the last reset
+    pub count: u64,
+    /// 50th percentile duration, in microseconds
+    pub p50_us: u64,
+    /// 90th percentile duration, in microseconds
+    pub p90_us: u64,
+    /// 99th percentile duration, in microseconds
+    pub p99_us: u64,
+    /// Slowest observed duration, in microseconds
+    pub max_us: u64,
+}
+
+/// Fixed, log2-bucketed latency histogram: recording is three relaxed
+/// atomic operations per call (bucket and count increments plus a max
+/// update; no allocation, no lock), so it's cheap enough for a hot path. Bucket `i` counts samples in `[2^(i-1), 2^i)` microseconds
+/// (bucket 0 covers 0 microseconds exactly).
+#[derive(Debug)]
+pub struct BucketHistogram {
+    buckets: [std::sync::atomic::AtomicU64; NUM_BUCKETS],
+    count: std::sync::atomic::AtomicU64,
+    max_us: std::sync::atomic::AtomicU64,
+}
+
+impl Default for BucketHistogram {
+    fn default() -> Self {
+        Self {
+            buckets: std::array::from_fn(|_| std::sync::atomic::AtomicU64::new(0)),
+            count: std::sync::atomic::AtomicU64::new(0),
+            max_us: std::sync::atomic::AtomicU64::new(0),
+        }
+    }
+}
+
+impl BucketHistogram {
+    /// Create an empty histogram
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn bucket_index(duration_us: u64) -> usize {
+        if duration_us == 0 {
+            0
+        } else {
+            (64 - duration_us.leading_zeros()) as usize
+        }
+        .min(NUM_BUCKETS - 1)
+    }
+
+    fn bucket_upper_bound(index: usize) -> u64 {
+        if index == 0 {
+            0
+        } else {
+            1u64 << index
+        }
+    }
+
+    /// Record one observed duration, in microseconds
+    pub fn record_us(&self, duration_us: u64) {
+        use std::sync::atomic::Ordering;
+
+        self.buckets[Self::bucket_index(duration_us)].fetch_add(1, Ordering::Relaxed);
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.max_us.fetch_max(duration_us, Ordering::Relaxed);
+    }
+
+    /// Point-in-time snapshot of p50/p90/p99/max
+    pub fn snapshot(&self) -> LatencyQuantiles {
+        use std::sync::atomic::Ordering;
+
+        let total = self.count.load(Ordering::Relaxed);
+        if total == 0 {
+            return LatencyQuantiles::default();
+        }
+
+        let counts: Vec<u64> = self
+            .buckets
+            .iter()
+            .map(|b| b.load(Ordering::Relaxed))
+            .collect();
+
+        let percentile = |p: f64| -> u64 {
+            let target = ((total as f64) * p).ceil() as u64;
+            let mut cumulative = 0u64;
+            for (index, bucket_count) in counts.iter().enumerate() {
+                cumulative += bucket_count;
+                if cumulative >= target {
+                    return Self::bucket_upper_bound(index);
+                }
+            }
+            Self::bucket_upper_bound(NUM_BUCKETS - 1)
+        };
+
+        LatencyQuantiles {
+            count: total,
+            p50_us: percentile(0.50),
+            p90_us: percentile(0.90),
+            p99_us: percentile(0.99),
+            max_us: self.max_us.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Discard all recorded samples, for windowed sampling
+    pub fn reset(&self) {
+        use std::sync::atomic::Ordering;
+
+        for bucket in &self.buckets {
+            bucket.store(0, Ordering::Relaxed);
+        }
+        self.count.store(0, Ordering::Relaxed);
+        self.max_us.store(0, Ordering::Relaxed);
+    }
+}
+
+/// RAII guard that records its own lifetime (in microseconds) into a
+/// [`BucketHistogram`] on `Drop`.
+pub struct ScopedUsTimer<'a> {
+    start: Instant,
+    histogram: &'a BucketHistogram,
+}
+
+impl<'a> ScopedUsTimer<'a> {
+    /// Start timing, recording into `histogram` when the guard is dropped
+    pub fn new(histogram: &'a BucketHistogram) -> Self {
+        Self {
+            start: Instant::now(),
+            histogram,
+        }
+    }
+}
+
+impl Drop for ScopedUsTimer<'_> {
+    fn drop(&mut self) {
+        self.histogram
+            .record_us(self.start.elapsed().as_micros() as u64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_histogram_empty_snapshot() {
+        let hist = LatencyHistogram::new();
+        assert_eq!(hist.snapshot(), LatencyStats::default());
+    }
+
+    #[test]
+    fn test_histogram_min_max_mean() {
+        let hist = LatencyHistogram::new();
+        for ns in [100, 300, 200] {
+            hist.record(ns);
+        }
+
+        let stats = hist.snapshot();
+        assert_eq!(stats.count, 3);
+        assert_eq!(stats.min_ns, 100);
+        assert_eq!(stats.max_ns, 300);
+        assert_eq!(stats.mean_ns, 200);
+    }
+
+    #[test]
+    fn test_histogram_reset_clears_samples() {
+        let hist = LatencyHistogram::new();
+        hist.record(500);
+        hist.reset();
+        assert_eq!(hist.snapshot(), LatencyStats::default());
+    }
+
+    #[test]
+    fn test_scoped_timer_records_on_drop() {
+        let hist = LatencyHistogram::new();
+        {
+            let _timer = ScopedTimer::new(&hist);
+        }
+        assert_eq!(hist.snapshot().count, 1);
+    }
+
+    #[test]
+    fn test_bucket_histogram_empty_snapshot() {
+        let hist = BucketHistogram::new();
+        assert_eq!(hist.snapshot(), LatencyQuantiles::default());
+    }
+
+    #[test]
+    fn test_bucket_histogram_percentiles_and_max() {
+        let hist = BucketHistogram::new();
+        for us in 1..=100u64 {
+            hist.record_us(us);
+        }
+
+        let stats = hist.snapshot();
+        assert_eq!(stats.count, 100);
+        assert_eq!(stats.max_us, 100);
+        // Bucket-upper-bound estimates are coarse; just check monotonicity
+        // and that they land in the right order of magnitude.
+        assert!(stats.p50_us <= stats.p90_us);
+        assert!(stats.p90_us <= stats.p99_us);
+        assert!(stats.p99_us <= stats.max_us * 2);
+    }
+
+    #[test]
+    fn test_bucket_histogram_reset_clears_samples() {
+        let hist = BucketHistogram::new();
+        hist.record_us(500);
+        hist.reset();
+        assert_eq!(hist.snapshot(), LatencyQuantiles::default());
+    }
+
+    #[test]
+    fn test_scoped_us_timer_records_on_drop() {
+        let hist = BucketHistogram::new();
+        {
+            let _timer = ScopedUsTimer::new(&hist);
+        }
+        assert_eq!(hist.snapshot().count, 1);
+    }
+}
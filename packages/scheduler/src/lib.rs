@@ -32,18 +32,35 @@
 #![warn(missing_docs)]
 #![warn(clippy::all)]
 
+pub mod circuit_breaker;
 pub mod config;
+pub mod coordination;
 pub mod cynic_client;
 pub mod error;
+pub mod event_sink;
+#[cfg(feature = "latency-metrics")]
+pub mod latency;
+pub mod lru_cache;
+pub mod prio_graph;
 pub mod priority;
 pub mod scheduler;
+pub mod shm_ring;
 
 // Re-exports
-pub use config::SchedulerConfig;
+pub use circuit_breaker::{BreakerHealth, BreakerState, ResilienceConfig};
+pub use config::{ApiErrorPolicies, ApiErrorPolicy, SchedulerConfig, SchedulingMode};
+pub use coordination::{CoordinationBackend, InMemoryCoordinationBackend, PeerLease};
 pub use cynic_client::CynicClient;
-pub use error::{SchedulerError, Result};
-pub use priority::{PriorityQueue, TransactionPriority};
+pub use error::{CynicApiErrorCategory, Result, SchedulerError};
+pub use event_sink::{BackpressurePolicy, EventSink, EventSinks, SchedulerEvent};
+#[cfg(feature = "latency-metrics")]
+pub use latency::{
+    BucketHistogram, LatencyHistogram, LatencyQuantiles, LatencyStats, ScopedTimer, ScopedUsTimer,
+};
+pub use prio_graph::schedule_conflict_free;
+pub use priority::{PriorityQueue, QueuedTransaction, TransactionPriority};
 pub use scheduler::{CynicScheduler, SchedulerState, SchedulerStats};
+pub use shm_ring::{ShmProgress, ShmRing, ShmTxSlot};
 
 /// φ (Golden Ratio)
 pub const PHI: f64 = 1.618033988749895;
@@ -98,6 +115,11 @@ pub struct ReputationScore {
     pub e_score: Option<f64>,
     /// Confidence (max 61.8%)
     pub confidence: f64,
+    /// Whether this score was served from a cache entry past `cache_ttl`
+    /// while a background refresh was in flight, rather than freshly
+    /// fetched (or within its TTL)
+    #[serde(default)]
+    pub is_stale: bool,
 }
 
 impl Default for ReputationScore {
@@ -108,6 +130,7 @@ impl Default for ReputationScore {
             k_score: None,
             e_score: None,
             confidence: PHI_INV * 100.0, // 61.8%
+            is_stale: false,
         }
     }
 }
@@ -0,0 +1,210 @@
+//! Fixed-capacity O(1) LRU cache
+//!
+//! A `HashMap` combined with an intrusive doubly linked list over a slab of
+//! slots, so `get` (promote to most-recently-used) and capacity-triggered
+//! eviction of the least-recently-used entry are both O(1) instead of the
+//! O(n) scan-and-`retain` a plain `HashMap` would need.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+struct Node<K, V> {
+    key: K,
+    value: V,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+/// Fixed-capacity cache with O(1) `get`/`insert` and O(1) LRU eviction.
+pub struct LruCache<K, V> {
+    slots: Vec<Node<K, V>>,
+    index: HashMap<K, usize>,
+    /// Most-recently-used slot
+    head: Option<usize>,
+    /// Least-recently-used slot
+    tail: Option<usize>,
+    capacity: usize,
+    /// Total evictions performed since creation (high-water mark for callers
+    /// that want to know how often capacity pressure is kicking in)
+    evictions: u64,
+}
+
+impl<K: Eq + Hash + Clone, V> LruCache<K, V> {
+    /// Create an empty cache bounded to `capacity` entries (capacity of 0
+    /// means every insert evicts immediately)
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            slots: Vec::new(),
+            index: HashMap::new(),
+            head: None,
+            tail: None,
+            capacity,
+            evictions: 0,
+        }
+    }
+
+    /// Number of entries currently cached
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    /// Whether the cache currently holds no entries
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    /// Total LRU evictions performed since creation
+    pub fn evictions(&self) -> u64 {
+        self.evictions
+    }
+
+    /// Look up `key`, promoting it to most-recently-used on a hit
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let &slot = self.index.get(key)?;
+        self.detach(slot);
+        self.attach_front(slot);
+        Some(&self.slots[slot].value)
+    }
+
+    /// Insert or overwrite `key`, evicting the least-recently-used entry in
+    /// O(1) if the cache is at capacity
+    pub fn insert(&mut self, key: K, value: V) {
+        if let Some(&slot) = self.index.get(&key) {
+            self.slots[slot].value = value;
+            self.detach(slot);
+            self.attach_front(slot);
+            return;
+        }
+
+        if self.capacity == 0 {
+            self.evictions += 1;
+            return;
+        }
+
+        if self.index.len() >= self.capacity {
+            if let Some(lru) = self.tail {
+                self.detach(lru);
+                let evicted_key = self.slots[lru].key.clone();
+                self.index.remove(&evicted_key);
+                self.evictions += 1;
+                let slot = lru;
+                self.slots[slot] = Node {
+                    key: key.clone(),
+                    value,
+                    prev: None,
+                    next: None,
+                };
+                self.index.insert(key, slot);
+                self.attach_front(slot);
+                return;
+            }
+        }
+
+        let slot = self.slots.len();
+        self.slots.push(Node {
+            key: key.clone(),
+            value,
+            prev: None,
+            next: None,
+        });
+        self.index.insert(key, slot);
+        self.attach_front(slot);
+    }
+
+    /// Remove all entries, keeping the eviction high-water mark intact
+    pub fn clear(&mut self) {
+        self.slots.clear();
+        self.index.clear();
+        self.head = None;
+        self.tail = None;
+    }
+
+    /// Iterate over all cached values in no particular order
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.slots.iter().map(|node| &node.value)
+    }
+
+    fn detach(&mut self, slot: usize) {
+        let (prev, next) = (self.slots[slot].prev, self.slots[slot].next);
+
+        match prev {
+            Some(p) => self.slots[p].next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(n) => self.slots[n].prev = prev,
+            None => self.tail = prev,
+        }
+
+        self.slots[slot].prev = None;
+        self.slots[slot].next = None;
+    }
+
+    fn attach_front(&mut self, slot: usize) {
+        self.slots[slot].prev = None;
+        self.slots[slot].next = self.head;
+        if let Some(old_head) = self.head {
+            self.slots[old_head].prev = Some(slot);
+        }
+        self.head = Some(slot);
+        if self.tail.is_none() {
+            self.tail = Some(slot);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_and_get() {
+        let mut cache = LruCache::new(2);
+        cache.insert("a", 1);
+        cache.insert("b", 2);
+
+        assert_eq!(cache.get(&"a"), Some(&1));
+        assert_eq!(cache.get(&"b"), Some(&2));
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let mut cache = LruCache::new(2);
+        cache.insert("a", 1);
+        cache.insert("b", 2);
+
+        // Touch "a" so "b" becomes the least-recently-used.
+        cache.get(&"a");
+        cache.insert("c", 3);
+
+        assert_eq!(cache.get(&"b"), None);
+        assert_eq!(cache.get(&"a"), Some(&1));
+        assert_eq!(cache.get(&"c"), Some(&3));
+        assert_eq!(cache.evictions(), 1);
+    }
+
+    #[test]
+    fn test_overwrite_existing_key_does_not_evict() {
+        let mut cache = LruCache::new(2);
+        cache.insert("a", 1);
+        cache.insert("b", 2);
+        cache.insert("a", 100);
+
+        assert_eq!(cache.get(&"a"), Some(&100));
+        assert_eq!(cache.get(&"b"), Some(&2));
+        assert_eq!(cache.evictions(), 0);
+    }
+
+    #[test]
+    fn test_clear_resets_entries_but_keeps_eviction_count() {
+        let mut cache = LruCache::new(1);
+        cache.insert("a", 1);
+        cache.insert("b", 2);
+        assert_eq!(cache.evictions(), 1);
+
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(cache.evictions(), 1);
+    }
+}
@@ -1,10 +1,12 @@
 //! Priority queue for φ-weighted transaction scheduling
 
+#[cfg(feature = "latency-metrics")]
+use crate::{BucketHistogram, LatencyHistogram, LatencyQuantiles, ScopedTimer};
 use crate::{ReputationScore, Result, SchedulerError, Verdict, PHI, PHI_INV};
 use parking_lot::Mutex;
 use priority_queue::PriorityQueue as InnerPriorityQueue;
 use std::cmp::Ordering;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -113,20 +115,101 @@ pub struct QueuedTransaction {
     pub tx_offset: usize,
     /// Raw transaction bytes length
     pub tx_length: u32,
+    /// Account keys this transaction locks for writing, used by
+    /// `prio_graph::schedule_conflict_free` to detect write↔write and
+    /// read↔write conflicts between transactions
+    pub writable_accounts: Vec<String>,
+    /// Account keys this transaction only reads, used the same way. Two
+    /// transactions that both merely read an account never conflict.
+    pub readonly_accounts: Vec<String>,
 }
 
 /// Thread-safe priority queue for transactions
 pub struct PriorityQueue {
     inner: Arc<Mutex<PriorityQueueInner>>,
     max_size: usize,
+    /// Base minimum-effective-priority floor (φ-score)
+    base_floor: f64,
+    /// Maximum queued transactions per fee payer (0 = unlimited)
+    max_per_payer: usize,
+    /// `enqueue` latency, recorded independently of `inner`'s mutex
+    #[cfg(feature = "latency-metrics")]
+    enqueue_latency: LatencyHistogram,
+    /// `dequeue`/`dequeue_batch` latency, recorded independently of `inner`'s mutex
+    #[cfg(feature = "latency-metrics")]
+    dequeue_latency: LatencyHistogram,
+    /// Time spent sitting in the queue between `enqueue` and `dequeue`/`dequeue_batch`
+    #[cfg(feature = "latency-metrics")]
+    residency_latency: BucketHistogram,
 }
 
 struct PriorityQueueInner {
     queue: InnerPriorityQueue<String, TransactionPriority>,
     transactions: HashMap<String, QueuedTransaction>,
+    /// Secondary min-indexed structure kept in sync with `queue` on every
+    /// push/pop/replace, so the worst (lowest `phi_score`) entry can be found
+    /// in O(log n) instead of scanning the max-ordered `queue`.
+    min_index: BTreeMap<TransactionPriority, HashSet<String>>,
+    /// Queued transaction count per `fee_payer`, for fairness quotas
+    payer_counts: HashMap<String, usize>,
     stats: QueueStats,
 }
 
+impl PriorityQueueInner {
+    fn index_insert(&mut self, sig: String, priority: TransactionPriority) {
+        self.min_index.entry(priority).or_default().insert(sig);
+    }
+
+    fn index_remove(&mut self, sig: &str, priority: TransactionPriority) {
+        if let Some(sigs) = self.min_index.get_mut(&priority) {
+            sigs.remove(sig);
+            if sigs.is_empty() {
+                self.min_index.remove(&priority);
+            }
+        }
+    }
+
+    fn payer_incr(&mut self, fee_payer: &str) {
+        *self.payer_counts.entry(fee_payer.to_string()).or_insert(0) += 1;
+    }
+
+    fn payer_decr(&mut self, fee_payer: &str) {
+        if let Some(count) = self.payer_counts.get_mut(fee_payer) {
+            *count -= 1;
+            if *count == 0 {
+                self.payer_counts.remove(fee_payer);
+            }
+        }
+    }
+
+    /// Remove a transaction by signature, keeping the queue, min-index and
+    /// per-payer counts consistent. Returns the removed transaction, if any.
+    fn remove_transaction(
+        &mut self,
+        sig: &str,
+        priority: TransactionPriority,
+    ) -> Option<QueuedTransaction> {
+        self.queue.remove(sig);
+        self.index_remove(sig, priority);
+        let tx = self.transactions.remove(sig)?;
+        self.payer_decr(&tx.fee_payer);
+        Some(tx)
+    }
+
+    /// Current worst (lowest `phi_score`) queued entry belonging to `fee_payer`, if any.
+    fn payer_worst_entry(&self, fee_payer: &str) -> Option<(String, TransactionPriority)> {
+        self.transactions
+            .values()
+            .filter(|tx| tx.fee_payer == fee_payer)
+            .filter_map(|tx| {
+                self.queue
+                    .get_priority(&tx.signature)
+                    .map(|p| (tx.signature.clone(), *p))
+            })
+            .min_by_key(|(_, p)| *p)
+    }
+}
+
 /// Queue statistics
 #[derive(Debug, Clone, Default)]
 pub struct QueueStats {
@@ -140,25 +223,121 @@ pub struct QueueStats {
     pub total_boosted: u64,
     /// Total transactions reduced (BARK)
     pub total_reduced: u64,
+    /// Total transactions rejected for falling below the effective priority floor
+    pub rejected_below_floor: u64,
+    /// Total resubmissions of an already-queued signature that replaced the existing entry
+    pub replaced: u64,
+    /// Total resubmissions of an already-queued signature rejected (not strictly better)
+    pub rejected_duplicate: u64,
+    /// Total transactions rejected because the queue was full and they didn't
+    /// strictly beat the worst queued entry
+    pub rejected_full_queue: u64,
+    /// Total transactions rejected for exceeding their fee payer's quota
+    /// without beating that payer's own worst queued entry
+    pub rejected_payer_quota: u64,
     /// Current queue size
     pub current_size: usize,
+    /// Top fee payers by queued transaction count, highest first (for
+    /// spotting address-level spam)
+    pub top_payers: Vec<(String, usize)>,
+    /// `enqueue` latency histogram (min/max/mean/p99, in nanoseconds)
+    #[cfg(feature = "latency-metrics")]
+    pub enqueue_latency_ns: crate::LatencyStats,
+    /// `dequeue`/`dequeue_batch` latency histogram (min/max/mean/p99, in nanoseconds)
+    #[cfg(feature = "latency-metrics")]
+    pub dequeue_latency_ns: crate::LatencyStats,
+    /// Queue residency time (enqueue → dequeue) percentiles, in microseconds
+    #[cfg(feature = "latency-metrics")]
+    pub queue_residency_us: LatencyQuantiles,
 }
 
+/// How many payers `PriorityQueue::stats` reports in `top_payers`
+const TOP_PAYERS_REPORTED: usize = 10;
+
 impl PriorityQueue {
     /// Create a new priority queue
     pub fn new(max_size: usize) -> Self {
+        Self::with_floor(max_size, 0.0)
+    }
+
+    /// Create a new priority queue with a configured base minimum-priority floor
+    pub fn with_floor(max_size: usize, base_floor: f64) -> Self {
+        Self::with_config(max_size, base_floor, 0)
+    }
+
+    /// Create a new priority queue with a configured base minimum-priority
+    /// floor and per-fee-payer fairness quota (`max_per_payer` of 0 = unlimited)
+    pub fn with_config(max_size: usize, base_floor: f64, max_per_payer: usize) -> Self {
         Self {
             inner: Arc::new(Mutex::new(PriorityQueueInner {
                 queue: priority_queue::PriorityQueue::new(),
                 transactions: HashMap::new(),
+                min_index: BTreeMap::new(),
+                payer_counts: HashMap::new(),
                 stats: QueueStats::default(),
             })),
             max_size,
+            base_floor,
+            max_per_payer,
+            #[cfg(feature = "latency-metrics")]
+            enqueue_latency: LatencyHistogram::new(),
+            #[cfg(feature = "latency-metrics")]
+            dequeue_latency: LatencyHistogram::new(),
+            #[cfg(feature = "latency-metrics")]
+            residency_latency: BucketHistogram::new(),
+        }
+    }
+
+    /// Current worst (lowest `phi_score`) queued entry, if any, in O(log n)
+    /// via the `min_index` rather than scanning the max-ordered `queue`.
+    fn worst_entry(inner: &PriorityQueueInner) -> Option<(String, TransactionPriority)> {
+        inner.min_index.iter().next().map(|(priority, sigs)| {
+            let sig = sigs
+                .iter()
+                .next()
+                .expect("min_index never stores empty sig sets")
+                .clone();
+            (sig, *priority)
+        })
+    }
+
+    /// Current lowest-priority queued transaction's `TransactionPriority`, if any.
+    pub fn worst(&self) -> Option<TransactionPriority> {
+        Self::worst_entry(&self.inner.lock()).map(|(_, p)| p)
+    }
+
+    /// Effective minimum-priority floor given current occupancy.
+    ///
+    /// Equals `base_floor` on an empty queue (or when the worst in-queue
+    /// `phi_score` is at or below it); otherwise rises from `base_floor`
+    /// toward the current worst in-queue `phi_score` with the square of
+    /// occupancy, reaching it when the queue holds `max_size` entries, so
+    /// that under pressure only transactions that could actually win
+    /// admission are accepted.
+    fn effective_floor(&self, inner: &PriorityQueueInner) -> f64 {
+        let Some((_, worst)) = Self::worst_entry(inner) else {
+            return self.base_floor;
+        };
+        if worst.phi_score <= self.base_floor {
+            return self.base_floor;
         }
+
+        let occupancy = inner.queue.len() as f64 / self.max_size as f64;
+        let saturation = occupancy.clamp(0.0, 1.0).powi(2);
+        self.base_floor + (worst.phi_score - self.base_floor) * saturation
     }
 
-    /// Enqueue a transaction with reputation-based priority
+    /// Enqueue a transaction with reputation-based priority.
+    ///
+    /// Resubmission of an already-queued `signature` is treated as a
+    /// replace-by-fee: the existing entry is replaced only if the new
+    /// `phi_score` is strictly greater, otherwise the existing entry is kept.
+    /// When the queue is full and the signature is new, the globally-worst
+    /// entry is evicted only if the new priority strictly beats it.
     pub fn enqueue(&self, tx: QueuedTransaction, reputation: &ReputationScore) -> Result<bool> {
+        #[cfg(feature = "latency-metrics")]
+        let _timer = ScopedTimer::new(&self.enqueue_latency);
+
         let mut inner = self.inner.lock();
 
         // Check if should drop (GROWL)
@@ -167,21 +346,64 @@ impl PriorityQueue {
             return Ok(false);
         }
 
-        // Check queue capacity
-        if inner.queue.len() >= self.max_size {
-            // Drop lowest priority if new tx has higher priority
-            let priority = TransactionPriority::new(tx.priority_fee, reputation);
-            if let Some((_, lowest)) = inner.queue.peek() {
-                if priority <= *lowest {
-                    return Err(SchedulerError::queue("Queue full, transaction priority too low"));
-                }
-                // Remove lowest priority
-                inner.queue.pop();
+        // Calculate priority
+        let priority = TransactionPriority::new(tx.priority_fee, reputation);
+
+        // Reject anything that couldn't win admission under current pressure
+        let floor = self.effective_floor(&inner);
+        if priority.phi_score < floor {
+            inner.stats.rejected_below_floor += 1;
+            return Err(SchedulerError::below_min_priority(format!(
+                "phi_score {:.4} below effective floor {:.4}",
+                priority.phi_score, floor
+            )));
+        }
+
+        // Resubmission of an already-queued signature: replace-by-fee
+        if let Some(existing) = inner.queue.get_priority(&tx.signature).copied() {
+            if priority > existing {
+                inner.queue.change_priority(&tx.signature, priority);
+                inner.index_remove(&tx.signature, existing);
+                inner.index_insert(tx.signature.clone(), priority);
+                inner.transactions.insert(tx.signature.clone(), tx);
+                inner.stats.replaced += 1;
+                return Ok(true);
             }
+            inner.stats.rejected_duplicate += 1;
+            return Ok(false);
         }
 
-        // Calculate priority
-        let priority = TransactionPriority::new(tx.priority_fee, reputation);
+        // Per-fee-payer fairness quota: a payer already at quota may only
+        // displace their own worst queued entry, never another sender's.
+        let payer_count = inner.payer_counts.get(&tx.fee_payer).copied().unwrap_or(0);
+        if self.max_per_payer > 0 && payer_count >= self.max_per_payer {
+            match inner.payer_worst_entry(&tx.fee_payer) {
+                Some((worst_sig, worst_priority)) if priority > worst_priority => {
+                    inner.remove_transaction(&worst_sig, worst_priority);
+                }
+                _ => {
+                    inner.stats.rejected_payer_quota += 1;
+                    return Ok(false);
+                }
+            }
+        } else if inner.queue.len() >= self.max_size {
+            // Check queue capacity: evict the globally-worst entry, but only
+            // if the new transaction strictly beats it (equal scores never evict).
+            match Self::worst_entry(&inner) {
+                Some((worst_sig, worst_priority)) if priority > worst_priority => {
+                    inner.remove_transaction(&worst_sig, worst_priority);
+                }
+                Some(_) => {
+                    inner.stats.rejected_full_queue += 1;
+                    return Ok(false);
+                }
+                None => {
+                    return Err(SchedulerError::queue(
+                        "Queue full, no eviction candidate found",
+                    ));
+                }
+            }
+        }
 
         // Track boost/reduce
         match reputation.verdict {
@@ -192,8 +414,10 @@ impl PriorityQueue {
 
         // Insert
         let sig = tx.signature.clone();
+        inner.payer_incr(&tx.fee_payer);
         inner.transactions.insert(sig.clone(), tx);
-        inner.queue.push(sig, priority);
+        inner.queue.push(sig.clone(), priority);
+        inner.index_insert(sig, priority);
         inner.stats.total_enqueued += 1;
         inner.stats.current_size = inner.queue.len();
 
@@ -202,12 +426,21 @@ impl PriorityQueue {
 
     /// Dequeue highest priority transaction
     pub fn dequeue(&self) -> Option<QueuedTransaction> {
+        #[cfg(feature = "latency-metrics")]
+        let _timer = ScopedTimer::new(&self.dequeue_latency);
+
         let mut inner = self.inner.lock();
 
-        if let Some((sig, _)) = inner.queue.pop() {
+        if let Some((sig, priority)) = inner.queue.pop() {
+            inner.index_remove(&sig, priority);
             inner.stats.total_dequeued += 1;
             inner.stats.current_size = inner.queue.len();
-            return inner.transactions.remove(&sig);
+            let tx = inner.transactions.remove(&sig)?;
+            inner.payer_decr(&tx.fee_payer);
+            #[cfg(feature = "latency-metrics")]
+            self.residency_latency
+                .record_us(priority.timestamp.elapsed().as_micros() as u64);
+            return Some(tx);
         }
 
         None
@@ -215,12 +448,20 @@ impl PriorityQueue {
 
     /// Dequeue up to `n` highest priority transactions
     pub fn dequeue_batch(&self, n: usize) -> Vec<QueuedTransaction> {
+        #[cfg(feature = "latency-metrics")]
+        let _timer = ScopedTimer::new(&self.dequeue_latency);
+
         let mut inner = self.inner.lock();
         let mut batch = Vec::with_capacity(n);
 
         for _ in 0..n {
-            if let Some((sig, _)) = inner.queue.pop() {
+            if let Some((sig, priority)) = inner.queue.pop() {
+                inner.index_remove(&sig, priority);
                 if let Some(tx) = inner.transactions.remove(&sig) {
+                    inner.payer_decr(&tx.fee_payer);
+                    #[cfg(feature = "latency-metrics")]
+                    self.residency_latency
+                        .record_us(priority.timestamp.elapsed().as_micros() as u64);
                     batch.push(tx);
                 }
             } else {
@@ -244,9 +485,47 @@ impl PriorityQueue {
         self.inner.lock().queue.is_empty()
     }
 
-    /// Get queue statistics
+    /// Get queue statistics, including the top fee payers by queued count
     pub fn stats(&self) -> QueueStats {
-        self.inner.lock().stats.clone()
+        let inner = self.inner.lock();
+        let mut stats = inner.stats.clone();
+
+        let mut payers: Vec<(String, usize)> = inner
+            .payer_counts
+            .iter()
+            .map(|(payer, count)| (payer.clone(), *count))
+            .collect();
+        payers.sort_by(|a, b| b.1.cmp(&a.1));
+        payers.truncate(TOP_PAYERS_REPORTED);
+        stats.top_payers = payers;
+
+        #[cfg(feature = "latency-metrics")]
+        {
+            stats.enqueue_latency_ns = self.enqueue_latency.snapshot();
+            stats.dequeue_latency_ns = self.dequeue_latency.snapshot();
+            stats.queue_residency_us = self.residency_latency.snapshot();
+        }
+
+        stats
+    }
+
+    /// Reset the accumulated `enqueue`/`dequeue`/residency latency histograms,
+    /// for windowed sampling (e.g. report-then-reset on a fixed interval).
+    #[cfg(feature = "latency-metrics")]
+    pub fn reset_latency(&self) {
+        self.enqueue_latency.reset();
+        self.dequeue_latency.reset();
+        self.residency_latency.reset();
+    }
+
+    /// Get queued transaction count for a specific fee payer
+    pub fn payer_depth(&self, fee_payer: &str) -> usize {
+        self.inner
+            .lock()
+            .payer_counts
+            .get(fee_payer)
+            .copied()
+            .unwrap_or(0)
     }
 
     /// Clear the queue
@@ -254,6 +533,8 @@ impl PriorityQueue {
         let mut inner = self.inner.lock();
         inner.queue.clear();
         inner.transactions.clear();
+        inner.min_index.clear();
+        inner.payer_counts.clear();
         inner.stats.current_size = 0;
     }
 }
@@ -263,14 +544,20 @@ mod tests {
     use super::*;
 
     fn make_tx(sig: &str, fee: u64) -> QueuedTransaction {
+        make_tx_payer(sig, fee, "test_payer")
+    }
+
+    fn make_tx_payer(sig: &str, fee: u64, payer: &str) -> QueuedTransaction {
         QueuedTransaction {
             signature: sig.to_string(),
-            fee_payer: "test_payer".to_string(),
+            fee_payer: payer.to_string(),
             priority_fee: fee,
             compute_units: 200_000,
             reputation: None,
             tx_offset: 0,
             tx_length: 100,
+            writable_accounts: Vec::new(),
+            readonly_accounts: Vec::new(),
         }
     }
 
@@ -349,6 +636,236 @@ mod tests {
         assert_eq!(stats.total_dropped, 1);
     }
 
+    #[test]
+    fn test_min_priority_floor_rejects_below_base() {
+        let queue = PriorityQueue::with_floor(100, 500.0);
+        let rep = ReputationScore::default();
+
+        let low = make_tx("low_fee", 100); // phi_score 100 < floor 500
+        assert!(queue.enqueue(low, &rep).is_err());
+        assert_eq!(queue.stats().rejected_below_floor, 1);
+
+        let high = make_tx("high_fee", 1000); // phi_score 1000 >= floor
+        assert!(queue.enqueue(high, &rep).unwrap());
+    }
+
+    #[test]
+    fn test_min_priority_floor_rises_with_occupancy() {
+        // Small queue so it's easy to push occupancy toward max_size.
+        let queue = PriorityQueue::with_floor(2, 0.0);
+        let rep = ReputationScore::default();
+
+        queue.enqueue(make_tx("a", 10_000), &rep).unwrap();
+        queue.enqueue(make_tx("b", 10_000), &rep).unwrap();
+
+        // Queue is now at capacity with worst phi_score 10_000; a low-fee tx
+        // has no chance of winning eviction and should be rejected by the
+        // dynamic floor rather than just falling through to the full-queue path.
+        let tiny = make_tx("tiny", 1);
+        assert!(queue.enqueue(tiny, &rep).is_err());
+        assert_eq!(queue.stats().rejected_below_floor, 1);
+    }
+
+    #[test]
+    fn test_resubmit_strictly_better_replaces() {
+        let queue = PriorityQueue::new(100);
+        let rep = ReputationScore::default();
+
+        queue.enqueue(make_tx("sig1", 100), &rep).unwrap();
+        assert!(queue.enqueue(make_tx("sig1", 500), &rep).unwrap());
+
+        assert_eq!(queue.len(), 1);
+        assert_eq!(queue.stats().replaced, 1);
+        assert_eq!(queue.dequeue().unwrap().priority_fee, 500);
+    }
+
+    #[test]
+    fn test_resubmit_not_strictly_better_rejected() {
+        let queue = PriorityQueue::new(100);
+        let rep = ReputationScore::default();
+
+        queue.enqueue(make_tx("sig1", 500), &rep).unwrap();
+        // Same fee (equal phi_score) should NOT displace the existing entry.
+        assert!(!queue.enqueue(make_tx("sig1", 500), &rep).unwrap());
+        assert!(!queue.enqueue(make_tx("sig1", 100), &rep).unwrap());
+
+        assert_eq!(queue.len(), 1);
+        assert_eq!(queue.stats().rejected_duplicate, 2);
+        assert_eq!(queue.dequeue().unwrap().priority_fee, 500);
+    }
+
+    #[test]
+    fn test_full_queue_equal_priority_does_not_evict() {
+        let queue = PriorityQueue::new(1);
+        let rep = ReputationScore::default();
+
+        queue.enqueue(make_tx("first", 100), &rep).unwrap();
+        // Equal phi_score must not trigger pointless eviction of "first".
+        assert!(!queue.enqueue(make_tx("second", 100), &rep).unwrap());
+
+        assert_eq!(queue.len(), 1);
+        assert_eq!(queue.stats().rejected_full_queue, 1);
+        assert_eq!(queue.dequeue().unwrap().signature, "first");
+    }
+
+    #[test]
+    fn test_full_queue_strictly_better_evicts_worst() {
+        let queue = PriorityQueue::new(1);
+        let rep = ReputationScore::default();
+
+        queue.enqueue(make_tx("low", 100), &rep).unwrap();
+        assert!(queue.enqueue(make_tx("high", 1000), &rep).unwrap());
+
+        assert_eq!(queue.len(), 1);
+        assert_eq!(queue.dequeue().unwrap().signature, "high");
+    }
+
+    #[test]
+    fn test_worst_accessor_tracks_min_index() {
+        let queue = PriorityQueue::new(100);
+        let rep = ReputationScore::default();
+
+        assert!(queue.worst().is_none());
+
+        queue.enqueue(make_tx("mid", 500), &rep).unwrap();
+        queue.enqueue(make_tx("low", 100), &rep).unwrap();
+        queue.enqueue(make_tx("high", 1000), &rep).unwrap();
+
+        assert_eq!(queue.worst().unwrap().base_fee, 100);
+
+        // Dequeuing the best entry shouldn't disturb the worst.
+        queue.dequeue().unwrap();
+        assert_eq!(queue.worst().unwrap().base_fee, 100);
+    }
+
+    #[test]
+    fn test_min_index_consistent_under_interleaved_ops() {
+        let queue = PriorityQueue::new(5);
+        let rep = ReputationScore::default();
+
+        // Fill the queue, then interleave replace-by-fee, full-queue eviction,
+        // and dequeues, and check that the O(log n) `worst()` accessor agrees
+        // with the lowest of the entries that remain queued at the end.
+        for i in 0..5 {
+            queue
+                .enqueue(make_tx(&format!("tx{}", i), 100 * (i + 1)), &rep)
+                .unwrap();
+        }
+
+        // Resubmit tx0 with a higher fee (replace-by-fee).
+        queue.enqueue(make_tx("tx0", 10_000), &rep).unwrap();
+
+        // Queue is full; a higher-fee newcomer should evict the current worst.
+        queue.enqueue(make_tx("tx5", 50_000), &rep).unwrap();
+
+        // Dequeue the best a couple of times.
+        queue.dequeue().unwrap();
+        queue.dequeue().unwrap();
+
+        // One more insertion after partial draining (fee kept above the
+        // current dynamic floor so this exercises the min-index, not chunk0-1's
+        // admission floor).
+        queue.enqueue(make_tx("tx6", 1000), &rep).unwrap();
+
+        // Surviving entries are tx2(300), tx3(400), tx4(500), tx6(1000); the
+        // lowest is tx2.
+        assert_eq!(queue.worst().unwrap().base_fee, 300);
+    }
+
+    #[test]
+    fn test_payer_quota_rejects_when_not_strictly_better() {
+        let queue = PriorityQueue::with_config(100, 0.0, 2);
+        let rep = ReputationScore::default();
+
+        queue
+            .enqueue(make_tx_payer("a1", 100, "alice"), &rep)
+            .unwrap();
+        queue
+            .enqueue(make_tx_payer("a2", 200, "alice"), &rep)
+            .unwrap();
+        assert_eq!(queue.payer_depth("alice"), 2);
+
+        // At quota: a new, lower-fee transaction from alice shouldn't
+        // displace alice's worst.
+        assert!(!queue
+            .enqueue(make_tx_payer("a3", 50, "alice"), &rep)
+            .unwrap());
+        assert_eq!(queue.stats().rejected_payer_quota, 1);
+        assert_eq!(queue.payer_depth("alice"), 2);
+    }
+
+    #[test]
+    fn test_payer_quota_replaces_own_worst_not_others() {
+        let queue = PriorityQueue::with_config(100, 0.0, 2);
+        let rep = ReputationScore::default();
+
+        queue
+            .enqueue(make_tx_payer("a1", 100, "alice"), &rep)
+            .unwrap();
+        queue
+            .enqueue(make_tx_payer("a2", 200, "alice"), &rep)
+            .unwrap();
+        queue
+            .enqueue(make_tx_payer("b1", 1000, "bob"), &rep)
+            .unwrap();
+
+        // At quota: a strictly-better fee should evict alice's own worst (a1),
+        // never bob's entry.
+        assert!(queue
+            .enqueue(make_tx_payer("a3", 500, "alice"), &rep)
+            .unwrap());
+        assert_eq!(queue.payer_depth("alice"), 2);
+        assert_eq!(queue.payer_depth("bob"), 1);
+        assert_eq!(queue.len(), 3);
+
+        let remaining: Vec<String> = (0..3)
+            .filter_map(|_| queue.dequeue())
+            .map(|t| t.signature)
+            .collect();
+        assert!(remaining.contains(&"a2".to_string()));
+        assert!(remaining.contains(&"a3".to_string()));
+        assert!(remaining.contains(&"b1".to_string()));
+        assert!(!remaining.contains(&"a1".to_string()));
+    }
+
+    #[test]
+    fn test_payer_counts_track_batch_dequeue() {
+        let queue = PriorityQueue::with_config(100, 0.0, 0);
+        let rep = ReputationScore::default();
+
+        for i in 0..5 {
+            queue
+                .enqueue(make_tx_payer(&format!("tx{}", i), 100, "alice"), &rep)
+                .unwrap();
+        }
+        assert_eq!(queue.payer_depth("alice"), 5);
+
+        queue.dequeue_batch(3);
+        assert_eq!(queue.payer_depth("alice"), 2);
+
+        queue.clear();
+        assert_eq!(queue.payer_depth("alice"), 0);
+    }
+
+    #[test]
+    fn test_top_payers_sorted_by_occupancy() {
+        let queue = PriorityQueue::with_config(100, 0.0, 0);
+        let rep = ReputationScore::default();
+
+        for i in 0..3 {
+            queue
+                .enqueue(make_tx_payer(&format!("a{}", i), 100, "alice"), &rep)
+                .unwrap();
+        }
+        queue
+            .enqueue(make_tx_payer("b1", 100, "bob"), &rep)
+            .unwrap();
+
+        let top = queue.stats().top_payers;
+        assert_eq!(top[0], ("alice".to_string(), 3));
+        assert_eq!(top[1], ("bob".to_string(), 1));
+    }
+
     #[test]
     fn test_batch_dequeue() {
         let queue = PriorityQueue::new(100);
@@ -1,17 +1,33 @@
 //! Main CYNIC Scheduler implementation
 
+#[cfg(feature = "latency-metrics")]
+use crate::{BucketHistogram, ScopedUsTimer};
 use crate::{
-    config::SchedulerConfig,
+    config::{ApiErrorPolicy, SchedulerConfig, SchedulingMode},
+    coordination::{CoordinationBackend, PeerLease},
     cynic_client::CynicClient,
-    error::{Result, SchedulerError},
+    error::{CynicApiErrorCategory, Result, SchedulerError},
+    event_sink::{EventSink, EventSinks, SchedulerEvent},
+    prio_graph,
     priority::{PriorityQueue, QueuedTransaction, QueueStats},
-    ReputationScore,
+    shm_ring::{ShmProgress, ShmRing, ShmTxSlot},
+    ReputationScore, ResilienceConfig, Verdict,
 };
 use parking_lot::RwLock;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use tracing::{debug, info, warn};
 
+/// Slot count for every shared-memory ring this scheduler owns. Must be a
+/// power of two; generous enough to absorb a worker stall without the TPU
+/// side observing backpressure under normal load.
+const SHM_RING_CAPACITY: u64 = 4096;
+
+/// How long a queue poll loop sleeps after finding its ring empty, to avoid
+/// busy-spinning a whole core per queue between real traffic.
+const QUEUE_POLL_IDLE_BACKOFF: Duration = Duration::from_micros(200);
+
 /// Scheduler state
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SchedulerState {
@@ -25,6 +41,48 @@ pub enum SchedulerState {
     Stopping,
 }
 
+/// Per-category count of CYNIC API failures since scheduler creation, so
+/// operators can see when (and how) CYNIC is degraded
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CynicApiErrorCounts {
+    /// `CynicApiErrorCategory::Unauthorized` count
+    pub unauthorized: u64,
+    /// `CynicApiErrorCategory::RateLimited` count
+    pub rate_limited: u64,
+    /// `CynicApiErrorCategory::NotFound` count
+    pub not_found: u64,
+    /// `CynicApiErrorCategory::Timeout` count
+    pub timeout: u64,
+    /// `CynicApiErrorCategory::Transport` count
+    pub transport: u64,
+    /// `CynicApiErrorCategory::Malformed` count
+    pub malformed: u64,
+}
+
+impl CynicApiErrorCounts {
+    /// Increment the counter for `category`
+    fn record(&mut self, category: CynicApiErrorCategory) {
+        match category {
+            CynicApiErrorCategory::Unauthorized => self.unauthorized += 1,
+            CynicApiErrorCategory::RateLimited => self.rate_limited += 1,
+            CynicApiErrorCategory::NotFound => self.not_found += 1,
+            CynicApiErrorCategory::Timeout => self.timeout += 1,
+            CynicApiErrorCategory::Transport => self.transport += 1,
+            CynicApiErrorCategory::Malformed => self.malformed += 1,
+        }
+    }
+
+    /// Total failures across every category
+    pub fn total(&self) -> u64 {
+        self.unauthorized
+            + self.rate_limited
+            + self.not_found
+            + self.timeout
+            + self.transport
+            + self.malformed
+    }
+}
+
 /// CYNIC Scheduler statistics
 #[derive(Debug, Clone, Default)]
 pub struct SchedulerStats {
@@ -48,6 +106,20 @@ pub struct SchedulerStats {
     pub cynic_api_calls: u64,
     /// API cache hits
     pub cynic_cache_hits: u64,
+    /// CYNIC API failures by category (Unauthorized, RateLimited, NotFound,
+    /// Timeout, Transport, Malformed)
+    pub cynic_api_errors: CynicApiErrorCounts,
+    /// Whether a multi-instance coordination backend is attached
+    pub coordination_attached: bool,
+    /// Live peer count (including this instance) as of the last heartbeat
+    /// refresh; 0 if no coordination backend is attached
+    pub coordination_peer_count: u64,
+    /// `get_reputation` latency percentiles, in microseconds
+    #[cfg(feature = "latency-metrics")]
+    pub reputation_latency_us: crate::LatencyQuantiles,
+    /// `process_transaction` end-to-end latency percentiles, in microseconds
+    #[cfg(feature = "latency-metrics")]
+    pub process_transaction_latency_us: crate::LatencyQuantiles,
 }
 
 /// CYNIC Scheduler - φ-weighted transaction scheduling for Solana
@@ -61,6 +133,28 @@ pub struct CynicScheduler {
     current_slot: Arc<AtomicU64>,
     is_leader: Arc<AtomicBool>,
     stats: Arc<RwLock<SchedulerStats>>,
+    /// `get_reputation` latency, recorded independently of `stats`'s lock
+    #[cfg(feature = "latency-metrics")]
+    reputation_latency: Arc<BucketHistogram>,
+    /// `process_transaction` end-to-end latency, recorded independently of `stats`'s lock
+    #[cfg(feature = "latency-metrics")]
+    process_tx_latency: Arc<BucketHistogram>,
+    /// TPU → Pack shared memory ring, populated by `start()`
+    tpu_queue: Arc<RwLock<Option<Arc<ShmRing>>>>,
+    /// One Pack → Worker shared memory ring per worker, populated by `start()`
+    worker_queues: Arc<RwLock<Vec<Arc<ShmRing>>>>,
+    /// One Worker → Pack shared memory ring per worker, populated by `start()`
+    result_queues: Arc<RwLock<Vec<Arc<ShmRing>>>>,
+    /// Progress tracker shared memory region, populated by `start()`
+    progress: Arc<RwLock<Option<Arc<ShmProgress>>>>,
+    /// Subscribers to verdict/result events
+    event_sinks: Arc<EventSinks>,
+    /// Multi-instance coordination backend, if attached via
+    /// `set_coordination_backend`
+    coordination: Arc<RwLock<Option<Arc<dyn CoordinationBackend>>>>,
+    /// Live peer count as of the last background heartbeat refresh, so
+    /// `stats()` can report it without itself becoming async
+    coordination_peer_count: Arc<AtomicU64>,
 }
 
 impl CynicScheduler {
@@ -72,10 +166,26 @@ impl CynicScheduler {
             &config.cynic_url,
             config.cynic_api_key.clone(),
             config.reputation_cache_ttl,
+            config.reputation_stale_ttl,
+            config.reputation_cache_capacity,
+            config.reputation_batch_size,
+            ResilienceConfig {
+                breaker_failure_threshold: config.breaker_failure_threshold,
+                breaker_cooldown: config.breaker_cooldown,
+                retry_max_attempts: config.retry_max_attempts,
+                retry_base_delay: config.retry_base_delay,
+                retry_max_delay: config.retry_max_delay,
+            },
+            config.api_error_policies(),
             config.api_timeout,
+            config.enable_shared_reputation_cache,
         )?);
 
-        let priority_queue = Arc::new(PriorityQueue::new(config.max_queue_size));
+        let priority_queue = Arc::new(PriorityQueue::with_config(
+            config.max_queue_size,
+            config.min_priority_floor,
+            config.max_per_payer,
+        ));
 
         Ok(Self {
             config,
@@ -86,6 +196,45 @@ impl CynicScheduler {
             current_slot: Arc::new(AtomicU64::new(0)),
             is_leader: Arc::new(AtomicBool::new(false)),
             stats: Arc::new(RwLock::new(SchedulerStats::default())),
+            #[cfg(feature = "latency-metrics")]
+            reputation_latency: Arc::new(BucketHistogram::new()),
+            #[cfg(feature = "latency-metrics")]
+            process_tx_latency: Arc::new(BucketHistogram::new()),
+            tpu_queue: Arc::new(RwLock::new(None)),
+            worker_queues: Arc::new(RwLock::new(Vec::new())),
+            result_queues: Arc::new(RwLock::new(Vec::new())),
+            progress: Arc::new(RwLock::new(None)),
+            event_sinks: Arc::new(EventSinks::new()),
+            coordination: Arc::new(RwLock::new(None)),
+            coordination_peer_count: Arc::new(AtomicU64::new(0)),
+        })
+    }
+
+    /// Subscribe `sink` to this scheduler's verdict/result events
+    /// (`SchedulerEvent::Dropped`/`Boosted`/`Reduced`/`Executed`)
+    pub fn subscribe(&self, sink: Arc<dyn EventSink>) {
+        self.event_sinks.subscribe(sink);
+    }
+
+    /// Attach a multi-instance coordination backend. Also attaches it to the
+    /// inner `CynicClient` as a read-through/write-through reputation cache
+    /// layer. Until attached, `update_progress` doesn't heartbeat and
+    /// `peers()` always returns an empty list.
+    pub fn set_coordination_backend(&self, backend: Arc<dyn CoordinationBackend>) {
+        self.cynic_client.set_coordination_backend(backend.clone());
+        *self.coordination.write() = Some(backend);
+    }
+
+    /// Peers with an unexpired heartbeat lease, as last observed via the
+    /// coordination backend (empty if none is attached, or on a backend
+    /// error)
+    pub async fn peers(&self) -> Vec<PeerLease> {
+        let Some(backend) = self.coordination.read().clone() else {
+            return Vec::new();
+        };
+        backend.live_peers().await.unwrap_or_else(|e| {
+            warn!(error = %e, "Failed to list coordination peers");
+            Vec::new()
         })
     }
 
@@ -106,10 +255,36 @@ impl CynicScheduler {
 
         self.running.store(true, Ordering::SeqCst);
 
-        // TODO: Initialize shared memory connections
-        // - Connect to tpu_to_pack queue
-        // - Connect to progress_tracker queue
-        // - Set up worker queues
+        // Create the TPU → Pack queue and one Pack/Worker queue pair per
+        // worker. If `start()` is racing a previous `stop()`'s cleanup of
+        // the same names this just recreates them, which is fine - each
+        // `ShmRing`/`ShmProgress` we create here owns (and will
+        // `shm_unlink`) only the region it itself created.
+        let tpu_queue = Arc::new(ShmRing::create(&self.config.tpu_to_pack_shm, SHM_RING_CAPACITY)?);
+
+        let mut worker_queues = Vec::with_capacity(self.config.num_workers);
+        let mut result_queues = Vec::with_capacity(self.config.num_workers);
+        for worker_id in 0..self.config.num_workers {
+            let pack_to_worker = format!("{}{}", self.config.pack_to_worker_shm_prefix, worker_id);
+            let worker_to_pack = format!("{}{}", self.config.worker_to_pack_shm_prefix, worker_id);
+            worker_queues.push(Arc::new(ShmRing::create(&pack_to_worker, SHM_RING_CAPACITY)?));
+            result_queues.push(Arc::new(ShmRing::create(&worker_to_pack, SHM_RING_CAPACITY)?));
+        }
+
+        let progress = Arc::new(ShmProgress::create(&self.config.progress_shm)?);
+
+        // Wire the queues up before handing ownership to the shared fields:
+        // each poll loop below holds its own `Arc` clone, so it keeps
+        // running against the region it started with even across a
+        // concurrent `stop()`'s `.take()`/`.clear()`.
+        self.spawn_tpu_poll_loop(tpu_queue.clone());
+        self.spawn_dispatch_loop(worker_queues.clone());
+        self.spawn_result_poll_loops(result_queues.clone());
+
+        *self.tpu_queue.write() = Some(tpu_queue);
+        *self.worker_queues.write() = worker_queues;
+        *self.result_queues.write() = result_queues;
+        *self.progress.write() = Some(progress);
 
         {
             let mut state = self.state.write();
@@ -120,6 +295,111 @@ impl CynicScheduler {
         Ok(())
     }
 
+    /// Poll `tpu_queue` for incoming transaction descriptors and run each
+    /// through `process_transaction`. `ShmTxSlot` only carries the fields a
+    /// worker needs to fetch and execute the raw transaction bytes - not the
+    /// writable/readonly account lists `process_transaction` also takes, per
+    /// `ShmTxSlot`'s own doc comment - so those cross as empty until the
+    /// wire format grows room for them.
+    fn spawn_tpu_poll_loop(&self, tpu_queue: Arc<ShmRing>) {
+        let scheduler = self.clone();
+        tokio::spawn(async move {
+            while scheduler.running.load(Ordering::SeqCst) {
+                match tpu_queue.pop() {
+                    Some(slot) => {
+                        if let Err(e) = scheduler
+                            .process_transaction(
+                                slot.signature().to_string(),
+                                slot.fee_payer().to_string(),
+                                slot.priority_fee,
+                                slot.compute_units,
+                                slot.tx_offset as usize,
+                                slot.tx_length,
+                                Vec::new(),
+                                Vec::new(),
+                            )
+                            .await
+                        {
+                            warn!(error = %e, "process_transaction failed");
+                        }
+                    }
+                    None => tokio::time::sleep(QUEUE_POLL_IDLE_BACKOFF).await,
+                }
+            }
+        });
+    }
+
+    /// Drain the priority queue and fan enqueued transactions out to worker
+    /// queues round-robin, so a `get_conflict_free_batches` result actually
+    /// reaches a worker instead of only being reachable by a caller polling
+    /// it directly. Goes through `get_conflict_free_batches` rather than
+    /// `get_batch` so `SchedulingMode::PrioGraph` packing is honored on this
+    /// path too: each returned sub-batch is internally write-conflict-free,
+    /// so it's pushed whole to a single worker and the round-robin advances
+    /// per sub-batch rather than per transaction, letting distinct sub-batches
+    /// run on distinct workers in parallel.
+    fn spawn_dispatch_loop(&self, worker_queues: Vec<Arc<ShmRing>>) {
+        if worker_queues.is_empty() {
+            return;
+        }
+
+        let scheduler = self.clone();
+        let batch_size = self.config.batch_size;
+        tokio::spawn(async move {
+            let mut next_worker = 0usize;
+            while scheduler.running.load(Ordering::SeqCst) {
+                let batches = scheduler.get_conflict_free_batches(batch_size);
+                if batches.iter().all(|batch| batch.is_empty()) {
+                    tokio::time::sleep(QUEUE_POLL_IDLE_BACKOFF).await;
+                    continue;
+                }
+
+                for batch in batches {
+                    if batch.is_empty() {
+                        continue;
+                    }
+
+                    let worker_id = next_worker;
+                    for tx in batch {
+                        let slot = ShmTxSlot::new(
+                            &tx.signature,
+                            &tx.fee_payer,
+                            tx.priority_fee,
+                            tx.compute_units,
+                            tx.tx_offset as u64,
+                            tx.tx_length,
+                        );
+                        if !worker_queues[worker_id].push(slot) {
+                            warn!(
+                                worker_id,
+                                "Pack → Worker queue full, dropping transaction"
+                            );
+                        }
+                    }
+                    next_worker = (next_worker + 1) % worker_queues.len();
+                }
+            }
+        });
+    }
+
+    /// Poll every worker's result queue and record outcomes via
+    /// `record_result`. `ShmTxSlot` has no dedicated success field, so the
+    /// result path reuses `compute_units` as a 0/1 flag - the only field of
+    /// an outgoing tx slot with no meaning on the way back.
+    fn spawn_result_poll_loops(&self, result_queues: Vec<Arc<ShmRing>>) {
+        for result_queue in result_queues {
+            let scheduler = self.clone();
+            tokio::spawn(async move {
+                while scheduler.running.load(Ordering::SeqCst) {
+                    match result_queue.pop() {
+                        Some(slot) => scheduler.record_result(slot.compute_units != 0),
+                        None => tokio::time::sleep(QUEUE_POLL_IDLE_BACKOFF).await,
+                    }
+                }
+            });
+        }
+    }
+
     /// Stop the scheduler
     pub async fn stop(&self) -> Result<()> {
         {
@@ -134,7 +414,12 @@ impl CynicScheduler {
 
         self.running.store(false, Ordering::SeqCst);
 
-        // TODO: Cleanup shared memory connections
+        // Dropping each handle unmaps it and, since this scheduler created
+        // every region in `start()`, `shm_unlink`s it too.
+        self.tpu_queue.write().take();
+        self.worker_queues.write().clear();
+        self.result_queues.write().clear();
+        self.progress.write().take();
 
         {
             let mut state = self.state.write();
@@ -146,6 +431,7 @@ impl CynicScheduler {
     }
 
     /// Process an incoming transaction from TPU
+    #[allow(clippy::too_many_arguments)]
     pub async fn process_transaction(
         &self,
         signature: String,
@@ -154,15 +440,33 @@ impl CynicScheduler {
         compute_units: u64,
         tx_offset: usize,
         tx_length: u32,
+        writable_accounts: Vec<String>,
+        readonly_accounts: Vec<String>,
     ) -> Result<bool> {
+        #[cfg(feature = "latency-metrics")]
+        let _timer = ScopedUsTimer::new(&self.process_tx_latency);
+
         // Update stats
         {
             let mut stats = self.stats.write();
             stats.tpu_received += 1;
         }
 
-        // Get reputation for fee payer
-        let reputation = self.get_reputation(&fee_payer).await;
+        // Get reputation for fee payer. A fail-closed policy on the
+        // category of whatever went wrong drops the transaction here rather
+        // than admitting it with a neutral score.
+        let reputation = match self.get_reputation(&fee_payer).await {
+            Ok(reputation) => reputation,
+            Err(e) => {
+                warn!(
+                    signature = %signature,
+                    fee_payer = %fee_payer,
+                    error = %e,
+                    "Dropping transaction: CYNIC reputation lookup failed closed"
+                );
+                return Ok(false);
+            }
+        };
 
         // Check GROWL filter
         if self.config.enable_growl_filter && reputation.verdict.should_drop() {
@@ -172,6 +476,10 @@ impl CynicScheduler {
                 verdict = ?reputation.verdict,
                 "Dropping GROWL transaction"
             );
+            self.event_sinks.emit(SchedulerEvent::Dropped {
+                signature,
+                verdict: reputation.verdict,
+            });
             return Ok(false);
         }
 
@@ -190,6 +498,7 @@ impl CynicScheduler {
         }
 
         // Create queued transaction
+        let signature_for_event = signature.clone();
         let tx = QueuedTransaction {
             signature,
             fee_payer,
@@ -198,19 +507,63 @@ impl CynicScheduler {
             reputation: Some(reputation.clone()),
             tx_offset,
             tx_length,
+            writable_accounts,
+            readonly_accounts,
         };
 
         // Enqueue with φ-weighted priority
-        self.priority_queue.enqueue(tx, &reputation)
+        let enqueued = self.priority_queue.enqueue(tx, &reputation)?;
+
+        if enqueued {
+            match reputation.verdict {
+                Verdict::Wag => self.event_sinks.emit(SchedulerEvent::Boosted {
+                    signature: signature_for_event,
+                }),
+                Verdict::Bark => self.event_sinks.emit(SchedulerEvent::Reduced {
+                    signature: signature_for_event,
+                }),
+                _ => {}
+            }
+        }
+
+        Ok(enqueued)
     }
 
-    /// Get reputation for an address (with caching)
-    async fn get_reputation(&self, address: &str) -> ReputationScore {
+    /// Get reputation for an address (with caching). Applies
+    /// `SchedulerConfig::policy_for` to a classified CYNIC API failure:
+    /// `FailOpen`/`UseDefault` resolve to `ReputationScore::default()`,
+    /// `FailClosed` propagates the error so the caller can drop the
+    /// transaction instead of admitting unvetted traffic.
+    async fn get_reputation(&self, address: &str) -> Result<ReputationScore> {
+        #[cfg(feature = "latency-metrics")]
+        let _timer = ScopedUsTimer::new(&self.reputation_latency);
+
         match self.cynic_client.get_wallet_reputation(address).await {
             Ok(score) => {
                 let mut stats = self.stats.write();
                 stats.cynic_api_calls += 1;
-                score
+                Ok(score)
+            }
+            Err(SchedulerError::CynicApiCategorized { category, message }) => {
+                let mut stats = self.stats.write();
+                stats.cynic_api_calls += 1;
+                stats.cynic_api_errors.record(category);
+                drop(stats);
+
+                match self.config.policy_for(category) {
+                    ApiErrorPolicy::FailOpen | ApiErrorPolicy::UseDefault => {
+                        debug!(
+                            address = %address,
+                            category = ?category,
+                            error = %message,
+                            "CYNIC reputation lookup failed, admitting with default score"
+                        );
+                        Ok(ReputationScore::default())
+                    }
+                    ApiErrorPolicy::FailClosed => Err(SchedulerError::cynic_api_categorized(
+                        category, message,
+                    )),
+                }
             }
             Err(e) => {
                 warn!(
@@ -218,7 +571,7 @@ impl CynicScheduler {
                     error = %e,
                     "Failed to get reputation, using default"
                 );
-                ReputationScore::default()
+                Ok(ReputationScore::default())
             }
         }
     }
@@ -236,25 +589,87 @@ impl CynicScheduler {
         batch
     }
 
+    /// Get up to `max_size` queued transactions packed per
+    /// `SchedulerConfig::scheduling_mode`.
+    ///
+    /// In `SchedulingMode::Simple` this returns `get_batch`'s flat result as
+    /// a single batch, unchanged from the original behavior. In
+    /// `SchedulingMode::PrioGraph` the batch is instead packed via
+    /// `prio_graph::schedule_conflict_free` into possibly several batches,
+    /// none of which contain two transactions that write-conflict - workers
+    /// can execute distinct returned batches in parallel without lock
+    /// contention.
+    pub fn get_conflict_free_batches(&self, max_size: usize) -> Vec<Vec<QueuedTransaction>> {
+        let batch = self.get_batch(max_size);
+
+        match self.config.scheduling_mode {
+            SchedulingMode::Simple => vec![batch],
+            SchedulingMode::PrioGraph => prio_graph::schedule_conflict_free(batch),
+        }
+    }
+
     /// Update slot/leader status from progress tracker
     pub fn update_progress(&self, slot: u64, is_leader: bool) {
         self.current_slot.store(slot, Ordering::SeqCst);
         self.is_leader.store(is_leader, Ordering::SeqCst);
 
+        if let Some(progress) = self.progress.read().as_ref() {
+            progress.write(slot, is_leader);
+        }
+
+        // Refresh this instance's heartbeat lease in the background, if a
+        // coordination backend is attached, so peers see an up-to-date
+        // leader flag without `update_progress` itself becoming async.
+        if let Some(backend) = self.coordination.read().clone() {
+            let instance_id = self.config.instance_id.clone();
+            let ttl = self.config.coordination_heartbeat_ttl;
+            let peer_count = self.coordination_peer_count.clone();
+            tokio::spawn(async move {
+                if let Err(e) = backend.heartbeat(&instance_id, is_leader, ttl).await {
+                    warn!(error = %e, "Failed to send coordination heartbeat");
+                }
+                match backend.live_peers().await {
+                    Ok(peers) => peer_count.store(peers.len() as u64, Ordering::SeqCst),
+                    Err(e) => warn!(error = %e, "Failed to list coordination peers"),
+                }
+            });
+        }
+
         let mut stats = self.stats.write();
         stats.current_slot = slot;
         stats.is_leader = is_leader;
     }
 
+    /// TPU → Pack shared memory ring, if the scheduler is running
+    pub fn tpu_queue(&self) -> Option<Arc<ShmRing>> {
+        self.tpu_queue.read().clone()
+    }
+
+    /// Pack → Worker shared memory ring for `worker_id`, if the scheduler is
+    /// running and `worker_id` is within `SchedulerConfig::num_workers`
+    pub fn worker_queue(&self, worker_id: usize) -> Option<Arc<ShmRing>> {
+        self.worker_queues.read().get(worker_id).cloned()
+    }
+
+    /// Worker → Pack shared memory ring for `worker_id`, if the scheduler is
+    /// running and `worker_id` is within `SchedulerConfig::num_workers`
+    pub fn result_queue(&self, worker_id: usize) -> Option<Arc<ShmRing>> {
+        self.result_queues.read().get(worker_id).cloned()
+    }
+
     /// Record execution result
     pub fn record_result(&self, success: bool) {
-        let mut stats = self.stats.write();
-        stats.results_received += 1;
-        if success {
-            stats.successful += 1;
-        } else {
-            stats.failed += 1;
+        {
+            let mut stats = self.stats.write();
+            stats.results_received += 1;
+            if success {
+                stats.successful += 1;
+            } else {
+                stats.failed += 1;
+            }
         }
+
+        self.event_sinks.emit(SchedulerEvent::Executed { success });
     }
 
     /// Get current statistics
@@ -263,8 +678,16 @@ impl CynicScheduler {
         stats.queue = self.priority_queue.stats();
 
         // Get cache stats
-        let (valid, _total) = self.cynic_client.cache_stats();
-        stats.cynic_cache_hits = valid as u64;
+        stats.cynic_cache_hits = self.cynic_client.cache_stats().valid as u64;
+
+        stats.coordination_attached = self.coordination.read().is_some();
+        stats.coordination_peer_count = self.coordination_peer_count.load(Ordering::SeqCst);
+
+        #[cfg(feature = "latency-metrics")]
+        {
+            stats.reputation_latency_us = self.reputation_latency.snapshot();
+            stats.process_transaction_latency_us = self.process_tx_latency.snapshot();
+        }
 
         stats
     }
@@ -309,7 +732,9 @@ impl std::fmt::Display for SchedulerStats {
              ├─ Dropped (GROWL): {}\n\
              ├─ Boosted (WAG): {}\n\
              ├─ Reduced (BARK): {}\n\
-             └─ CYNIC API: {} calls, {} cache hits",
+             ├─ CYNIC API: {} calls, {} cache hits\n\
+             ├─ CYNIC API errors: {} unauthorized, {} rate-limited, {} not-found, {} timeout, {} transport, {} malformed\n\
+             {} Coordination: {}",
             self.current_slot,
             if self.is_leader { "yes" } else { "no" },
             self.queue.current_size,
@@ -323,7 +748,46 @@ impl std::fmt::Display for SchedulerStats {
             self.queue.total_reduced,
             self.cynic_api_calls,
             self.cynic_cache_hits,
-        )
+            self.cynic_api_errors.unauthorized,
+            self.cynic_api_errors.rate_limited,
+            self.cynic_api_errors.not_found,
+            self.cynic_api_errors.timeout,
+            self.cynic_api_errors.transport,
+            self.cynic_api_errors.malformed,
+            if cfg!(feature = "latency-metrics") {
+                "├─"
+            } else {
+                "└─"
+            },
+            if self.coordination_attached {
+                format!("attached ({} peers)", self.coordination_peer_count)
+            } else {
+                "not attached".to_string()
+            },
+        )?;
+
+        #[cfg(feature = "latency-metrics")]
+        write!(
+            f,
+            "\n\
+             ├─ Queue residency (us): p50={} p90={} p99={} max={}\n\
+             ├─ Reputation fetch (us): p50={} p90={} p99={} max={}\n\
+             └─ Process tx (us): p50={} p90={} p99={} max={}",
+            self.queue.queue_residency_us.p50_us,
+            self.queue.queue_residency_us.p90_us,
+            self.queue.queue_residency_us.p99_us,
+            self.queue.queue_residency_us.max_us,
+            self.reputation_latency_us.p50_us,
+            self.reputation_latency_us.p90_us,
+            self.reputation_latency_us.p99_us,
+            self.reputation_latency_us.max_us,
+            self.process_transaction_latency_us.p50_us,
+            self.process_transaction_latency_us.p90_us,
+            self.process_transaction_latency_us.p99_us,
+            self.process_transaction_latency_us.max_us,
+        )?;
+
+        Ok(())
     }
 }
 
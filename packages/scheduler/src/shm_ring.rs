@@ -0,0 +1,592 @@
+//! Lock-free ring-buffer transport over named POSIX shared memory.
+//!
+//! Backs every queue `start()`/`stop()` previously only stubbed out:
+//! `tpu_to_pack_shm`, one `pack_to_worker_shm_prefix{n}` per worker, one
+//! `worker_to_pack_shm_prefix{n}` per worker, and `progress_shm`. Each queue
+//! lives in a single `shm_open` region containing a fixed-size header
+//! followed by a ring of fixed-size slots, so producers and consumers never
+//! allocate or lock on the hot path - just an atomic reservation per push/pop.
+//!
+//! The reservation scheme is Dmitry Vyukov's bounded MPMC ring buffer: each
+//! slot carries its own sequence number alongside the header's
+//! `enqueue_pos`/`dequeue_pos` counters, so multiple producers (or
+//! consumers) racing on the same region - e.g. several workers writing
+//! results back - never torn-read each other's in-flight slot.
+
+use crate::error::{Result, SchedulerError};
+use std::ffi::CString;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Base58-encoded signature is at most 88 bytes; fee payer pubkey at most 44.
+/// Both are zero-padded with an explicit length byte rather than storing a
+/// `String`, since shared memory is a flat byte region with no allocator.
+const MAX_SIGNATURE_LEN: usize = 88;
+const MAX_PUBKEY_LEN: usize = 44;
+
+/// One queued transaction descriptor, as laid out in shared memory.
+///
+/// Only the fields a worker actually needs to fetch and execute the raw
+/// transaction bytes (already sitting at `tx_offset` in the TPU's mapped
+/// transaction arena) cross this boundary - reputation, writable/readonly
+/// account lists, etc. stay in-process on [`crate::priority::QueuedTransaction`].
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ShmTxSlot {
+    signature: [u8; MAX_SIGNATURE_LEN],
+    signature_len: u8,
+    fee_payer: [u8; MAX_PUBKEY_LEN],
+    fee_payer_len: u8,
+    /// Priority fee in lamports per CU
+    pub priority_fee: u64,
+    /// Compute units requested
+    pub compute_units: u64,
+    /// Raw transaction bytes offset (for shared memory)
+    pub tx_offset: u64,
+    /// Raw transaction bytes length
+    pub tx_length: u32,
+}
+
+impl ShmTxSlot {
+    /// Build a slot from owned strings, truncating (rather than failing) on
+    /// the practically-impossible case of an oversized signature/pubkey -
+    /// this is a fixed-layout wire format, not a place to propagate errors
+    /// for inputs that can't occur on a real Solana transaction.
+    pub fn new(
+        signature: &str,
+        fee_payer: &str,
+        priority_fee: u64,
+        compute_units: u64,
+        tx_offset: u64,
+        tx_length: u32,
+    ) -> Self {
+        let mut slot = Self {
+            signature: [0u8; MAX_SIGNATURE_LEN],
+            signature_len: 0,
+            fee_payer: [0u8; MAX_PUBKEY_LEN],
+            fee_payer_len: 0,
+            priority_fee,
+            compute_units,
+            tx_offset,
+            tx_length,
+        };
+
+        let sig_bytes = &signature.as_bytes()[..signature.len().min(MAX_SIGNATURE_LEN)];
+        slot.signature[..sig_bytes.len()].copy_from_slice(sig_bytes);
+        slot.signature_len = sig_bytes.len() as u8;
+
+        let payer_bytes = &fee_payer.as_bytes()[..fee_payer.len().min(MAX_PUBKEY_LEN)];
+        slot.fee_payer[..payer_bytes.len()].copy_from_slice(payer_bytes);
+        slot.fee_payer_len = payer_bytes.len() as u8;
+
+        slot
+    }
+
+    /// Transaction signature, as written into the slot
+    pub fn signature(&self) -> &str {
+        std::str::from_utf8(&self.signature[..self.signature_len as usize]).unwrap_or_default()
+    }
+
+    /// Fee payer address, as written into the slot
+    pub fn fee_payer(&self) -> &str {
+        std::str::from_utf8(&self.fee_payer[..self.fee_payer_len as usize]).unwrap_or_default()
+    }
+}
+
+impl Default for ShmTxSlot {
+    fn default() -> Self {
+        Self {
+            signature: [0u8; MAX_SIGNATURE_LEN],
+            signature_len: 0,
+            fee_payer: [0u8; MAX_PUBKEY_LEN],
+            fee_payer_len: 0,
+            priority_fee: 0,
+            compute_units: 0,
+            tx_offset: 0,
+            tx_length: 0,
+        }
+    }
+}
+
+/// Per-slot sequence number plus payload, per Vyukov's bounded MPMC design.
+struct Cell {
+    sequence: AtomicU64,
+    data: std::cell::UnsafeCell<ShmTxSlot>,
+}
+
+// SAFETY: access to `data` is gated by `sequence`'s acquire/release
+// handshake in `push`/`pop`, the same way a `Mutex` gates its contents -
+// only one side ever observes a given sequence value at a time.
+unsafe impl Sync for Cell {}
+
+/// Ring-buffer header, mapped at the start of the shared memory region.
+/// `capacity` is set once by whichever side creates the region and never
+/// changes afterward, so later openers can trust it without synchronization.
+#[repr(C)]
+struct RingHeader {
+    capacity: u64,
+    enqueue_pos: AtomicU64,
+    dequeue_pos: AtomicU64,
+}
+
+/// A lock-free bounded queue mapped onto a named POSIX shared memory region.
+///
+/// `capacity` must be a power of two. The creating side (`create`) zero-
+/// initializes the region and `shm_unlink`s it on drop; an opening side
+/// (`open`) attaches to an already-created region and leaves it for the
+/// creator to clean up.
+pub struct ShmRing {
+    name: String,
+    capacity: u64,
+    mask: u64,
+    header: *mut RingHeader,
+    cells: *mut Cell,
+    map_len: usize,
+    owns_region: bool,
+}
+
+// SAFETY: `header` and `cells` point into a shared memory mapping that
+// outlives the `ShmRing`, and all access through them is atomic.
+unsafe impl Send for ShmRing {}
+unsafe impl Sync for ShmRing {}
+
+fn region_len(capacity: u64) -> usize {
+    std::mem::size_of::<RingHeader>() + (capacity as usize) * std::mem::size_of::<Cell>()
+}
+
+impl ShmRing {
+    /// Create (or truncate-and-recreate) a named shared memory ring with
+    /// room for `capacity` slots. `capacity` must be a power of two.
+    pub fn create(name: &str, capacity: u64) -> Result<Self> {
+        if capacity == 0 || (capacity & (capacity - 1)) != 0 {
+            return Err(SchedulerError::shared_memory(format!(
+                "ring capacity {} must be a non-zero power of two",
+                capacity
+            )));
+        }
+
+        let len = region_len(capacity);
+        let fd = Self::shm_open(name, true)?;
+        Self::truncate(fd, len)?;
+        let addr = Self::map(fd, len)?;
+
+        // SAFETY: `addr` points to a freshly-truncated (zeroed) mapping at
+        // least `len` bytes long; `RingHeader` and `Cell` are both valid to
+        // zero-initialize (atomics included - all-zero is a valid `AtomicU64`).
+        unsafe {
+            let header = addr as *mut RingHeader;
+            (*header).capacity = capacity;
+            (*header).enqueue_pos = AtomicU64::new(0);
+            (*header).dequeue_pos = AtomicU64::new(0);
+
+            let cells = addr.add(std::mem::size_of::<RingHeader>()) as *mut Cell;
+            for i in 0..capacity {
+                let cell = cells.add(i as usize);
+                (*cell).sequence = AtomicU64::new(i);
+                *(*cell).data.get() = ShmTxSlot::default();
+            }
+
+            libc::close(fd);
+
+            Ok(Self {
+                name: name.to_string(),
+                capacity,
+                mask: capacity - 1,
+                header,
+                cells,
+                map_len: len,
+                owns_region: true,
+            })
+        }
+    }
+
+    /// Attach to an already-created named shared memory ring.
+    pub fn open(name: &str, capacity: u64) -> Result<Self> {
+        let len = region_len(capacity);
+        let fd = Self::shm_open(name, false)?;
+        let addr = Self::map(fd, len)?;
+
+        // SAFETY: the creator already initialized this region with the same
+        // layout (`capacity` is agreed out-of-band by the caller; it is not
+        // read back from the header).
+        unsafe {
+            libc::close(fd);
+            let header = addr as *mut RingHeader;
+            let cells = addr.add(std::mem::size_of::<RingHeader>()) as *mut Cell;
+
+            Ok(Self {
+                name: name.to_string(),
+                capacity,
+                mask: capacity - 1,
+                header,
+                cells,
+                map_len: len,
+                owns_region: false,
+            })
+        }
+    }
+
+    fn shm_open(name: &str, create: bool) -> Result<i32> {
+        let cname = CString::new(name)
+            .map_err(|e| SchedulerError::shared_memory(format!("invalid shm name: {}", e)))?;
+
+        let flags = if create {
+            libc::O_CREAT | libc::O_RDWR
+        } else {
+            libc::O_RDWR
+        };
+
+        // SAFETY: FFI call with a valid NUL-terminated name and well-formed flags.
+        let fd = unsafe { libc::shm_open(cname.as_ptr(), flags, 0o600) };
+        if fd < 0 {
+            return Err(SchedulerError::shared_memory(format!(
+                "shm_open({}) failed: {}",
+                name,
+                std::io::Error::last_os_error()
+            )));
+        }
+        Ok(fd)
+    }
+
+    fn truncate(fd: i32, len: usize) -> Result<()> {
+        // SAFETY: `fd` is a valid shm file descriptor just opened above.
+        let rc = unsafe { libc::ftruncate(fd, len as libc::off_t) };
+        if rc != 0 {
+            // SAFETY: `fd` is still valid; we're only closing it on the error path.
+            unsafe {
+                libc::close(fd);
+            }
+            return Err(SchedulerError::shared_memory(format!(
+                "ftruncate failed: {}",
+                std::io::Error::last_os_error()
+            )));
+        }
+        Ok(())
+    }
+
+    fn map(fd: i32, len: usize) -> Result<*mut u8> {
+        // SAFETY: `fd` refers to a shm region at least `len` bytes long.
+        let addr = unsafe {
+            libc::mmap(
+                std::ptr::null_mut(),
+                len,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_SHARED,
+                fd,
+                0,
+            )
+        };
+        if addr == libc::MAP_FAILED {
+            // SAFETY: `fd` is still valid; we're only closing it on the error path.
+            unsafe {
+                libc::close(fd);
+            }
+            return Err(SchedulerError::shared_memory(format!(
+                "mmap failed: {}",
+                std::io::Error::last_os_error()
+            )));
+        }
+        Ok(addr as *mut u8)
+    }
+
+    fn header(&self) -> &RingHeader {
+        // SAFETY: valid for the lifetime of this `ShmRing`.
+        unsafe { &*self.header }
+    }
+
+    fn cell(&self, index: u64) -> &Cell {
+        // SAFETY: `index & self.mask` is always in `0..self.capacity`.
+        unsafe { &*self.cells.add((index & self.mask) as usize) }
+    }
+
+    /// Number of slots this ring holds
+    pub fn capacity(&self) -> u64 {
+        self.capacity
+    }
+
+    /// Push a slot onto the ring. Returns `false` without blocking if the
+    /// ring is currently full.
+    pub fn push(&self, value: ShmTxSlot) -> bool {
+        let header = self.header();
+        let mut pos = header.enqueue_pos.load(Ordering::Relaxed);
+
+        loop {
+            let cell = self.cell(pos);
+            let seq = cell.sequence.load(Ordering::Acquire);
+            let diff = seq as i64 - pos as i64;
+
+            if diff == 0 {
+                match header.enqueue_pos.compare_exchange_weak(
+                    pos,
+                    pos + 1,
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => break,
+                    Err(actual) => pos = actual,
+                }
+            } else if diff < 0 {
+                return false; // full
+            } else {
+                pos = header.enqueue_pos.load(Ordering::Relaxed);
+            }
+        }
+
+        let cell = self.cell(pos);
+        // SAFETY: this producer exclusively owns `cell` between winning the
+        // CAS above and publishing the new sequence number below.
+        unsafe {
+            *cell.data.get() = value;
+        }
+        cell.sequence.store(pos + 1, Ordering::Release);
+        true
+    }
+
+    /// Pop the oldest slot off the ring. Returns `None` without blocking if
+    /// the ring is currently empty.
+    pub fn pop(&self) -> Option<ShmTxSlot> {
+        let header = self.header();
+        let mut pos = header.dequeue_pos.load(Ordering::Relaxed);
+
+        loop {
+            let cell = self.cell(pos);
+            let seq = cell.sequence.load(Ordering::Acquire);
+            let diff = seq as i64 - (pos as i64 + 1);
+
+            if diff == 0 {
+                match header.dequeue_pos.compare_exchange_weak(
+                    pos,
+                    pos + 1,
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => break,
+                    Err(actual) => pos = actual,
+                }
+            } else if diff < 0 {
+                return None; // empty
+            } else {
+                pos = header.dequeue_pos.load(Ordering::Relaxed);
+            }
+        }
+
+        let cell = self.cell(pos);
+        // SAFETY: this consumer exclusively owns `cell` between winning the
+        // CAS above and publishing the wrapped-around sequence number below.
+        let value = unsafe { *cell.data.get() };
+        cell.sequence
+            .store(pos + self.mask + 1, Ordering::Release);
+        Some(value)
+    }
+}
+
+impl Drop for ShmRing {
+    fn drop(&mut self) {
+        // SAFETY: `self.header` is a valid mapping of `self.map_len` bytes
+        // for the lifetime of this `ShmRing`.
+        unsafe {
+            libc::munmap(self.header as *mut libc::c_void, self.map_len);
+        }
+
+        if self.owns_region {
+            if let Ok(cname) = CString::new(self.name.as_str()) {
+                // SAFETY: FFI call with a valid NUL-terminated name; failure
+                // (e.g. already unlinked) is not actionable here.
+                unsafe {
+                    libc::shm_unlink(cname.as_ptr());
+                }
+            }
+        }
+    }
+}
+
+/// Header for [`ShmProgress`], mapped at the start of its shared memory
+/// region. Slot/leader updates are frequent overwrites of the latest value
+/// rather than a queue, so this uses a seqlock instead of the ring's
+/// producer/consumer reservation scheme: `version` is odd while a write is
+/// in progress and even otherwise, and a reader retries if it observes a
+/// torn (odd, or changed mid-read) version.
+#[repr(C)]
+struct ProgressHeader {
+    version: AtomicU64,
+    slot: AtomicU64,
+    is_leader: AtomicU64,
+}
+
+/// Shared-memory seqlock holding the latest slot/leader status in the
+/// `progress_shm` region. `CynicScheduler::update_progress` publishes into
+/// it; the scheduler never reads it back, so slot/leader changes reach the
+/// scheduler only through direct `update_progress` calls.
+pub struct ShmProgress {
+    name: String,
+    header: *mut ProgressHeader,
+    map_len: usize,
+    owns_region: bool,
+}
+
+// SAFETY: all access to `header` goes through the seqlock protocol in
+// `write`/`read`, which fully orders writers against readers.
+unsafe impl Send for ShmProgress {}
+unsafe impl Sync for ShmProgress {}
+
+impl ShmProgress {
+    /// Create (or truncate-and-recreate) the named progress region.
+    pub fn create(name: &str) -> Result<Self> {
+        let len = std::mem::size_of::<ProgressHeader>();
+        let fd = ShmRing::shm_open(name, true)?;
+        ShmRing::truncate(fd, len)?;
+        let addr = ShmRing::map(fd, len)?;
+
+        // SAFETY: `addr` points to a mapping at least `len` bytes long,
+        // zeroed here (a pre-existing region of the same size keeps its old
+        // contents through `ftruncate`); an all-zero `ProgressHeader`
+        // (version 0, slot 0, leader false) is a valid initial state.
+        unsafe {
+            std::ptr::write_bytes(addr, 0, len);
+            libc::close(fd);
+            Ok(Self {
+                name: name.to_string(),
+                header: addr as *mut ProgressHeader,
+                map_len: len,
+                owns_region: true,
+            })
+        }
+    }
+
+    /// Attach to an already-created named progress region.
+    pub fn open(name: &str) -> Result<Self> {
+        let len = std::mem::size_of::<ProgressHeader>();
+        let fd = ShmRing::shm_open(name, false)?;
+        let addr = ShmRing::map(fd, len)?;
+
+        // SAFETY: the creator already initialized this region with the same layout.
+        unsafe {
+            libc::close(fd);
+            Ok(Self {
+                name: name.to_string(),
+                header: addr as *mut ProgressHeader,
+                map_len: len,
+                owns_region: false,
+            })
+        }
+    }
+
+    fn header(&self) -> &ProgressHeader {
+        // SAFETY: valid for the lifetime of this `ShmProgress`.
+        unsafe { &*self.header }
+    }
+
+    /// Publish the current slot and leader status.
+    pub fn write(&self, slot: u64, is_leader: bool) {
+        let header = self.header();
+        let version = header.version.fetch_add(1, Ordering::AcqRel); // now odd
+        header.slot.store(slot, Ordering::Relaxed);
+        header
+            .is_leader
+            .store(is_leader as u64, Ordering::Relaxed);
+        header.version.store(version + 2, Ordering::Release); // back to even
+    }
+
+    /// Read the current slot and leader status, retrying if a write raced
+    /// with the read.
+    pub fn read(&self) -> (u64, bool) {
+        let header = self.header();
+        loop {
+            let before = header.version.load(Ordering::Acquire);
+            if before % 2 != 0 {
+                std::hint::spin_loop();
+                continue;
+            }
+            let slot = header.slot.load(Ordering::Relaxed);
+            let is_leader = header.is_leader.load(Ordering::Relaxed) != 0;
+            let after = header.version.load(Ordering::Acquire);
+            if before == after {
+                return (slot, is_leader);
+            }
+        }
+    }
+}
+
+impl Drop for ShmProgress {
+    fn drop(&mut self) {
+        // SAFETY: `self.header` is a valid mapping of `self.map_len` bytes
+        // for the lifetime of this `ShmProgress`.
+        unsafe {
+            libc::munmap(self.header as *mut libc::c_void, self.map_len);
+        }
+
+        if self.owns_region {
+            if let Ok(cname) = CString::new(self.name.as_str()) {
+                // SAFETY: FFI call with a valid NUL-terminated name; failure
+                // (e.g. already unlinked) is not actionable here.
+                unsafe {
+                    libc::shm_unlink(cname.as_ptr());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unique_name(tag: &str) -> String {
+        format!("/cynic_test_{}_{:?}", tag, std::thread::current().id())
+    }
+
+    #[test]
+    fn test_create_and_push_pop_roundtrip() {
+        let name = unique_name("roundtrip");
+        let ring = ShmRing::create(&name, 8).unwrap();
+
+        let slot = ShmTxSlot::new("sig1", "payer1", 1000, 200_000, 0, 100);
+        assert!(ring.push(slot));
+
+        let popped = ring.pop().unwrap();
+        assert_eq!(popped.signature(), "sig1");
+        assert_eq!(popped.fee_payer(), "payer1");
+        assert_eq!(popped.priority_fee, 1000);
+        assert!(ring.pop().is_none());
+    }
+
+    #[test]
+    fn test_push_fails_when_full() {
+        let name = unique_name("full");
+        let ring = ShmRing::create(&name, 2).unwrap();
+
+        assert!(ring.push(ShmTxSlot::new("a", "p", 1, 1, 0, 1)));
+        assert!(ring.push(ShmTxSlot::new("b", "p", 1, 1, 0, 1)));
+        assert!(!ring.push(ShmTxSlot::new("c", "p", 1, 1, 0, 1)));
+    }
+
+    #[test]
+    fn test_fifo_order_preserved() {
+        let name = unique_name("fifo");
+        let ring = ShmRing::create(&name, 4).unwrap();
+
+        for i in 0..4 {
+            assert!(ring.push(ShmTxSlot::new(&format!("sig{}", i), "p", i, 1, 0, 1)));
+        }
+        for i in 0..4 {
+            assert_eq!(ring.pop().unwrap().signature(), format!("sig{}", i));
+        }
+        assert!(ring.pop().is_none());
+    }
+
+    #[test]
+    fn test_create_rejects_non_power_of_two() {
+        assert!(ShmRing::create(&unique_name("badcap"), 3).is_err());
+    }
+
+    #[test]
+    fn test_open_attaches_to_existing_region() {
+        let name = unique_name("attach");
+        let creator = ShmRing::create(&name, 4).unwrap();
+        assert!(creator.push(ShmTxSlot::new("sig1", "payer1", 42, 1, 0, 1)));
+
+        let opener = ShmRing::open(&name, 4).unwrap();
+        let popped = opener.pop().unwrap();
+        assert_eq!(popped.signature(), "sig1");
+        assert_eq!(popped.priority_fee, 42);
+    }
+}
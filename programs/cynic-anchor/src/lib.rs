@@ -9,6 +9,7 @@
  * creating an immutable record of the collective consciousness.
  */
 use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, MintTo, Token, TokenAccount};
 
 declare_id!("G3Yana4ukbevyoVNSWrXgRQtQqHYMnPEMi1xvpp9CqBY");
 
@@ -69,6 +70,35 @@ pub const SLASH_PERCENTAGE: u64 = 382;
 pub const SLASH_SCALE: u64 = 1000;
 /// Unstake cooldown in slots (F(13) = 233)
 pub const UNSTAKE_COOLDOWN: u64 = 233;
+/// Maximum unbonding chunks a validator can have pending at once (F(7) = 13)
+pub const MAX_UNLOCKING_CHUNKS: usize = 13;
+/// Fixed-point scale for sequential Phragmén load/score arithmetic
+pub const PHRAGMEN_SCALE: u128 = 1_000_000_000_000;
+/// Maximum unvested reward tranches a validator can have outstanding at
+/// once (F(6) = 8)
+pub const MAX_VESTING_TRANCHES: usize = 8;
+/// Default `withdrawal_timelock` set by `initialize`, in slots (F(13) = 233,
+/// same cadence as `UNSTAKE_COOLDOWN`): newly earned rewards clear their
+/// cliff this many slots after the tranche is created, then unlock linearly
+/// over an equal further window.
+pub const DEFAULT_WITHDRAWAL_TIMELOCK: u64 = 233;
+/// Basis point scale (100% = 10_000 bps)
+pub const BPS_SCALE: u64 = 10_000;
+/// Fixed-point scale for the delegation reward-per-share accumulator
+pub const REWARD_PER_SHARE_SCALE: u128 = 1_000_000_000_000; // 1e12
+/// Length of a reward era in slots (F(25) = 75025, ~8.3h at 400ms/slot)
+pub const REWARD_ERA_SLOTS: u64 = 75_025;
+/// Cap on the graduated slash fraction, in basis points (φ⁻² = 38.2%,
+/// matches the old flat `SLASH_PERCENTAGE`)
+pub const SLASH_BPS_CAP: u64 = 3_820;
+/// Scaling numerator for the correlated-offence slash fraction: a fully
+/// Byzantine validator set (offenders == active_validator_count) hits the
+/// cap; a lone offender is slashed a small fraction of it
+pub const SLASH_BASE_BPS: u64 = BPS_SCALE;
+/// Bonding window for slashing spans, in slots (F(27) = 196418, ~21.8h at
+/// 400ms/slot). An offence window older than this is pruned, letting a
+/// validator start a fresh span with `worst_fraction_bps` reset to 0.
+pub const SLASHING_BONDING_WINDOW_SLOTS: u64 = 196_418;
 
 /// Memo prefix for identification
 pub const MEMO_PREFIX: &[u8] = b"CYNIC:POJ:";
@@ -85,12 +115,34 @@ pub mod cynic_anchor {
         state.initialized_at = Clock::get()?.unix_timestamp;
         state.root_count = 0;
         state.validator_count = 0;
+        state.withdrawal_timelock = DEFAULT_WITHDRAWAL_TIMELOCK;
         state.bump = ctx.bumps.state;
 
         msg!("CYNIC Anchor initialized - κυνικός awakens on-chain");
         Ok(())
     }
 
+    /// Initialize reward era tracking. Only called once; creates the era
+    /// cursor and the first `RewardEra` (era 1).
+    pub fn initialize_reward_era(ctx: Context<InitializeRewardEra>) -> Result<()> {
+        let cursor = &mut ctx.accounts.era_cursor;
+        let era = &mut ctx.accounts.reward_era;
+
+        cursor.era_index = 1;
+        cursor.bump = ctx.bumps.era_cursor;
+
+        era.era_index = 1;
+        era.era_start_slot = Clock::get()?.slot;
+        era.total_points = 0;
+        era.reward_budget = 0;
+        era.rewards_distributed = 0;
+        era.closed = false;
+        era.bump = ctx.bumps.reward_era;
+
+        msg!("Reward era tracking initialized, era 1 started");
+        Ok(())
+    }
+
     /// Add a validator to the registry
     /// Only authority can add validators
     pub fn add_validator(ctx: Context<ManageValidator>, validator: Pubkey) -> Result<()> {
@@ -319,6 +371,24 @@ pub mod cynic_anchor {
         Ok(())
     }
 
+    /// Adjust how many slots a newly earned reward tranche must wait before
+    /// it starts unlocking (see `RewardVesting`). Only the program authority
+    /// may call this.
+    pub fn set_withdrawal_timelock(
+        ctx: Context<SetWithdrawalTimelock>,
+        withdrawal_timelock: u64,
+    ) -> Result<()> {
+        let state = &mut ctx.accounts.state;
+        state.withdrawal_timelock = withdrawal_timelock;
+
+        msg!("Withdrawal timelock set to {} slots", withdrawal_timelock);
+        emit!(WithdrawalTimelockUpdated {
+            withdrawal_timelock,
+        });
+
+        Ok(())
+    }
+
     /// Record a burn (penalty/slashing)
     /// Only validators can record burns
     /// BURN axiom: "Don't extract, burn" - simplicity through penalties
@@ -556,14 +626,29 @@ pub mod cynic_anchor {
 
     /// Stake SOL to become a validator
     /// Minimum stake required: MIN_VALIDATOR_STAKE (~0.1 SOL)
-    pub fn stake_validator(ctx: Context<StakeValidator>, amount: u64) -> Result<()> {
+    pub fn stake_validator(ctx: Context<StakeValidator>, amount: u64, commission_bps: u16) -> Result<()> {
         require!(
             amount >= MIN_VALIDATOR_STAKE,
             CynicError::InsufficientStake
         );
+        require!(
+            commission_bps as u64 <= BPS_SCALE,
+            CynicError::InvalidCommission
+        );
 
         let stake = &mut ctx.accounts.validator_stake;
         let staker = ctx.accounts.staker.key();
+        // `validator_stake` is `init_if_needed`, so this call may be a fresh
+        // account (all fields zeroed) or a reactivation after
+        // `request_unstake` dropped `is_active` without closing the account.
+        // Only a genuinely fresh account should reset reward-accounting
+        // state: `is_active` also goes false on a still-delegated validator
+        // that just dipped under `MIN_VALIDATOR_STAKE`, and zeroing
+        // `total_delegated`/`reward_per_share` there would erase delegators'
+        // already-accrued rewards and their share of future commission
+        // splits. `staked_at` is only ever 0 before the account's first
+        // `stake_validator` call, so check that instead of `is_active`.
+        let is_new = stake.staked_at == 0;
 
         // Transfer SOL to stake account (PDA holds the stake)
         let cpi_context = CpiContext::new(
@@ -579,13 +664,20 @@ pub mod cynic_anchor {
         stake.validator = staker;
         stake.staked_amount = stake.staked_amount.checked_add(amount).unwrap();
         stake.staked_at = Clock::get()?.unix_timestamp;
-        stake.last_anchor_slot = 0;
-        stake.anchor_count = 0;
-        stake.rewards_earned = 0;
-        stake.rewards_claimed = 0;
         stake.is_active = true;
+        stake.commission_bps = commission_bps;
+        if is_new {
+            stake.last_anchor_slot = 0;
+            stake.anchor_count = 0;
+            stake.rewards_earned = 0;
+            stake.rewards_claimed = 0;
+            stake.total_delegated = 0;
+            stake.reward_per_share = 0;
+            stake.points = 0;
+            stake.last_settled_era_index = 0;
+        }
 
-        msg!("Validator staked {} lamports", amount);
+        msg!("Validator staked {} lamports ({}bps commission)", amount, commission_bps);
 
         emit!(ValidatorStaked {
             validator: staker,
@@ -597,45 +689,96 @@ pub mod cynic_anchor {
         Ok(())
     }
 
-    /// Request unstake (starts cooldown period)
-    pub fn request_unstake(ctx: Context<RequestUnstake>) -> Result<()> {
+    /// Unbond `amount` lamports from active stake, pushing an `UnlockChunk`
+    /// that matures `UNSTAKE_COOLDOWN` slots from now. The remaining active
+    /// stake keeps earning rewards and counting toward validator weight;
+    /// `is_active` only drops once total backing (own stake plus delegated
+    /// stake, same `total_backing` computation `slash_validator` uses) falls
+    /// below `MIN_VALIDATOR_STAKE`. Chunks sharing an `unlock_slot` are merged
+    /// so repeated calls in the same slot don't grow the ledger.
+    pub fn request_unstake(ctx: Context<RequestUnstake>, amount: u64) -> Result<()> {
         let stake = &mut ctx.accounts.validator_stake;
 
         require!(stake.is_active, CynicError::ValidatorNotActive);
+        require!(
+            amount > 0 && amount <= stake.staked_amount,
+            CynicError::InvalidUnstakeAmount
+        );
+
+        let current_slot = Clock::get()?.slot;
+        let unlock_slot = current_slot + UNSTAKE_COOLDOWN;
+
+        let len = stake.unlocking_len as usize;
+        if let Some(chunk) = stake.unlocking[..len]
+            .iter_mut()
+            .find(|c| c.unlock_slot == unlock_slot)
+        {
+            chunk.value = chunk.value.checked_add(amount).unwrap();
+        } else {
+            require!(
+                len < MAX_UNLOCKING_CHUNKS,
+                CynicError::TooManyUnlockingChunks
+            );
+            stake.unlocking[len] = UnlockChunk {
+                value: amount,
+                unlock_slot,
+            };
+            stake.unlocking_len += 1;
+        }
 
-        stake.unstake_requested_slot = Clock::get()?.slot;
-        stake.is_active = false;
+        stake.staked_amount = stake.staked_amount.checked_sub(amount).unwrap();
+        stake.unstake_requested_slot = current_slot;
+        let total_backing = stake.staked_amount.saturating_add(stake.total_delegated);
+        stake.is_active = total_backing >= MIN_VALIDATOR_STAKE;
 
-        msg!("Unstake requested, cooldown started");
+        msg!(
+            "Unbonding {} lamports, matures at slot {}",
+            amount,
+            unlock_slot
+        );
 
         emit!(UnstakeRequested {
             validator: stake.validator,
+            amount,
             staked_amount: stake.staked_amount,
-            cooldown_ends_slot: stake.unstake_requested_slot + UNSTAKE_COOLDOWN,
+            cooldown_ends_slot: unlock_slot,
         });
 
         Ok(())
     }
 
-    /// Complete unstake after cooldown period
-    pub fn complete_unstake(ctx: Context<CompleteUnstake>) -> Result<()> {
-        let stake = &ctx.accounts.validator_stake;
+    /// Withdraw every unbonding chunk whose `unlock_slot` has passed,
+    /// transferring their sum back from `stake_vault`. If no active stake or
+    /// unlocking chunks remain afterward, the stake account is closed.
+    pub fn withdraw_unbonded(ctx: Context<WithdrawUnbonded>) -> Result<()> {
         let current_slot = Clock::get()?.slot;
 
-        require!(!stake.is_active, CynicError::ValidatorStillActive);
-        require!(
-            stake.unstake_requested_slot > 0,
-            CynicError::UnstakeNotRequested
-        );
-        require!(
-            current_slot >= stake.unstake_requested_slot + UNSTAKE_COOLDOWN,
-            CynicError::UnstakeCooldownNotComplete
-        );
+        let (amount, remaining_unlocking) = {
+            let stake = &mut ctx.accounts.validator_stake;
+            let len = stake.unlocking_len as usize;
+
+            let mut amount: u64 = 0;
+            let mut kept: Vec<UnlockChunk> = Vec::with_capacity(len);
+            for chunk in &stake.unlocking[..len] {
+                if chunk.unlock_slot <= current_slot {
+                    amount = amount.checked_add(chunk.value).unwrap();
+                } else {
+                    kept.push(*chunk);
+                }
+            }
+
+            require!(amount > 0, CynicError::UnstakeCooldownNotComplete);
+
+            for (i, chunk) in kept.iter().enumerate() {
+                stake.unlocking[i] = *chunk;
+            }
+            stake.unlocking_len = kept.len() as u8;
 
-        let amount = stake.staked_amount;
-        let validator = stake.validator;
+            (amount, kept.len())
+        };
+
+        let validator = ctx.accounts.validator_stake.validator;
 
-        // Transfer SOL back from vault to validator
         let bump = ctx.bumps.stake_vault;
         let seeds = &[b"stake_vault".as_ref(), &[bump]];
         let signer = &[&seeds[..]];
@@ -650,23 +793,107 @@ pub mod cynic_anchor {
         );
         anchor_lang::system_program::transfer(cpi_context, amount)?;
 
-        msg!("Unstake completed, {} lamports returned", amount);
+        let fully_exited = ctx.accounts.validator_stake.staked_amount == 0 && remaining_unlocking == 0;
+        if fully_exited {
+            ctx.accounts
+                .validator_stake
+                .close(ctx.accounts.validator.to_account_info())?;
+        }
+
+        msg!("Withdrew {} unbonded lamports", amount);
 
-        emit!(UnstakeCompleted {
+        emit!(UnbondedWithdrawn {
             validator,
             amount,
+            remaining_staked: if fully_exited {
+                0
+            } else {
+                ctx.accounts.validator_stake.staked_amount
+            },
+            remaining_unlocking: remaining_unlocking as u8,
+            closed: fully_exited,
             slot: current_slot,
         });
 
         Ok(())
     }
 
-    /// Claim accumulated rewards
-    pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+    /// Claim up to `amount` lamports of vested rewards. Unlike a raw
+    /// `rewards_earned - rewards_claimed` payout, newly-earned rewards first
+    /// sit in a `RewardVesting` tranche behind `state.withdrawal_timelock`
+    /// slots (the cliff), then unlock linearly over an equal further window -
+    /// discouraging anchor-and-dump behavior and smoothing reward outflow
+    /// from `reward_vault`.
+    pub fn claim_rewards(ctx: Context<ClaimRewards>, amount: u64) -> Result<()> {
+        let now = Clock::get()?.slot;
+        let timelock = ctx.accounts.state.withdrawal_timelock;
         let stake = &mut ctx.accounts.validator_stake;
-        let claimable = stake.rewards_earned.saturating_sub(stake.rewards_claimed);
+        let vesting = &mut ctx.accounts.reward_vesting;
 
-        require!(claimable > 0, CynicError::NoRewardsToClaim);
+        if vesting.validator == Pubkey::default() {
+            vesting.validator = stake.validator;
+            vesting.bump = ctx.bumps.reward_vesting;
+        }
+
+        // Tranche off anything earned since the last time this was called,
+        // if there's room in the queue. If the queue is full the delta stays
+        // un-tranched and is picked up by a future call.
+        let delta = stake.rewards_earned.saturating_sub(vesting.total_vested);
+        if delta > 0 && (vesting.tranche_len as usize) < MAX_VESTING_TRANCHES {
+            let idx = vesting.tranche_len as usize;
+            vesting.tranches[idx] = VestingTranche {
+                amount: delta,
+                start_slot: now,
+                cliff_slot: now.saturating_add(timelock),
+                end_slot: now.saturating_add(timelock.saturating_mul(2)),
+                claimed: 0,
+            };
+            vesting.tranche_len += 1;
+            vesting.total_vested = vesting.total_vested.checked_add(delta).unwrap();
+
+            msg!("Vested {} lamports into a new reward tranche", delta);
+            emit!(RewardVested {
+                validator: stake.validator,
+                amount: delta,
+                cliff_slot: vesting.tranches[idx].cliff_slot,
+                end_slot: vesting.tranches[idx].end_slot,
+            });
+        }
+
+        let len = vesting.tranche_len as usize;
+        let claimable: u64 = vesting.tranches[..len]
+            .iter()
+            .map(|t| linear_unlocked(t, now).saturating_sub(t.claimed))
+            .fold(0u64, |acc, x| acc.saturating_add(x));
+
+        require!(amount > 0 && amount <= claimable, CynicError::RewardsLocked);
+
+        // Consume `amount` FIFO, oldest tranche first.
+        let mut remaining = amount;
+        for t in vesting.tranches[..len].iter_mut() {
+            if remaining == 0 {
+                break;
+            }
+            let avail = linear_unlocked(t, now).saturating_sub(t.claimed);
+            let take = avail.min(remaining);
+            t.claimed = t.claimed.checked_add(take).unwrap();
+            remaining -= take;
+        }
+
+        // Drop every tranche that is fully claimed (and therefore fully
+        // vested), wherever it sits, compacting the rest down in order.
+        let mut kept: Vec<VestingTranche> = Vec::with_capacity(len);
+        for t in &vesting.tranches[..len] {
+            if t.claimed < t.amount {
+                kept.push(*t);
+            }
+        }
+        for (i, t) in kept.iter().enumerate() {
+            vesting.tranches[i] = *t;
+        }
+        vesting.tranche_len = kept.len() as u8;
+        vesting.total_claimed = vesting.total_claimed.checked_add(amount).unwrap();
+        stake.rewards_claimed = stake.rewards_claimed.checked_add(amount).unwrap();
 
         // Transfer rewards from vault to validator
         let bump = ctx.bumps.reward_vault;
@@ -681,239 +908,1339 @@ pub mod cynic_anchor {
             },
             signer,
         );
-        anchor_lang::system_program::transfer(cpi_context, claimable)?;
-
-        stake.rewards_claimed = stake.rewards_earned;
+        anchor_lang::system_program::transfer(cpi_context, amount)?;
 
-        msg!("Claimed {} lamports in rewards", claimable);
+        msg!("Claimed {} lamports in vested rewards", amount);
 
-        emit!(RewardsClaimed {
+        emit!(RewardUnlocked {
             validator: stake.validator,
-            amount: claimable,
+            amount,
             total_claimed: stake.rewards_claimed,
-            slot: Clock::get()?.slot,
+            slot: now,
         });
 
         Ok(())
     }
 
-    /// Slash a validator for misbehavior (BURN axiom)
-    /// Only authority can slash validators
+    /// Slash a validator for misbehavior (BURN axiom), graduated by how many
+    /// validators correlated on the same offence (Byzantine faults shared
+    /// across many validators are punished harder than isolated ones), and
+    /// idempotent both per `(validator, offence_slot)` and per slashing span
+    /// (re-reporting the same offence, or one at the same/lesser severity
+    /// within the current span, is a no-op).
+    ///
+    /// The loss cascades pro-rata to delegators: the same `delta_bps` cut
+    /// applied to the validator's own `staked_amount` is also applied to
+    /// every `Delegation` account passed in `remaining_accounts`, each
+    /// persisted via `exit()` since they aren't part of the static
+    /// `SlashValidator` accounts. A `SlashApplied` event fires per affected
+    /// account.
     pub fn slash_validator(
         ctx: Context<SlashValidator>,
+        offence_slot: u64,
         reason: u8,
+        offender_count: u32,
+        active_validator_count: u32,
         evidence_root: [u8; 32],
     ) -> Result<()> {
         let stake = &mut ctx.accounts.validator_stake;
+        let span = &mut ctx.accounts.slashing_span;
 
         require!(stake.is_active, CynicError::ValidatorNotActive);
         require!(reason <= BURN_REASON_SLASHING, CynicError::InvalidBurnReason);
+        require!(active_validator_count > 0, CynicError::InvalidOffenderCount);
+        require!(
+            offender_count > 0 && offender_count <= active_validator_count,
+            CynicError::InvalidOffenderCount
+        );
+
+        if span.start_slot == 0 {
+            // First offence ever reported for this validator.
+            span.validator = stake.validator;
+            span.start_slot = offence_slot;
+            span.worst_fraction_bps = 0;
+        } else if offence_slot == span.last_offence_slot {
+            msg!("Offence at slot {} already applied, no-op", offence_slot);
+            return Ok(());
+        } else if offence_slot >= span.start_slot + SLASHING_BONDING_WINDOW_SLOTS {
+            // Offence window expired; start a fresh span so the validator can heal.
+            span.span_index = span.span_index.checked_add(1).unwrap();
+            span.start_slot = offence_slot;
+            span.worst_fraction_bps = 0;
+        }
 
-        // Calculate slash amount (φ⁻² = 38.2% of stake)
-        let slash_amount = stake
-            .staked_amount
-            .checked_mul(SLASH_PERCENTAGE)
+        let fraction_bps = (SLASH_BASE_BPS as u128)
+            .checked_mul(offender_count as u128)
             .unwrap()
-            .checked_div(SLASH_SCALE)
-            .unwrap();
+            .checked_div(active_validator_count as u128)
+            .unwrap()
+            .min(SLASH_BPS_CAP as u128) as u64;
+
+        if fraction_bps <= span.worst_fraction_bps {
+            msg!(
+                "Offence fraction {}bps does not exceed span worst {}bps, no additional slash",
+                fraction_bps,
+                span.worst_fraction_bps
+            );
+            span.last_offence_slot = offence_slot;
+            return Ok(());
+        }
+
+        let delta_bps = fraction_bps - span.worst_fraction_bps;
+        let current_slot = Clock::get()?.slot;
+
+        let self_slash = (stake.staked_amount as u128)
+            .checked_mul(delta_bps as u128)
+            .unwrap()
+            .checked_div(BPS_SCALE as u128)
+            .unwrap() as u64;
+
+        stake.staked_amount = stake.staked_amount.saturating_sub(self_slash);
+
+        emit!(SlashApplied {
+            validator: stake.validator,
+            account: stake.validator,
+            amount: self_slash,
+            remaining: stake.staked_amount,
+            slot: current_slot,
+        });
+
+        let mut delegator_losses: u64 = 0;
+        for delegation_info in ctx.remaining_accounts.iter() {
+            let mut delegation: Account<Delegation> = Account::try_from(delegation_info)?;
+            require_keys_eq!(
+                delegation.validator,
+                stake.validator,
+                CynicError::DelegationValidatorMismatch
+            );
 
-        let old_stake = stake.staked_amount;
-        stake.staked_amount = stake.staked_amount.saturating_sub(slash_amount);
+            let loss = (delegation.amount as u128)
+                .checked_mul(delta_bps as u128)
+                .unwrap()
+                .checked_div(BPS_SCALE as u128)
+                .unwrap() as u64;
+
+            delegation.amount = delegation.amount.saturating_sub(loss);
+            delegator_losses = delegator_losses.checked_add(loss).unwrap();
+
+            emit!(SlashApplied {
+                validator: stake.validator,
+                account: delegation.delegator,
+                amount: loss,
+                remaining: delegation.amount,
+                slot: current_slot,
+            });
+
+            delegation.exit(&crate::ID)?;
+        }
+
+        stake.total_delegated = stake.total_delegated.saturating_sub(delegator_losses);
+
+        let total_slash = self_slash.checked_add(delegator_losses).unwrap();
         stake.slash_count += 1;
-        stake.total_slashed += slash_amount;
+        stake.total_slashed = stake.total_slashed.checked_add(total_slash).unwrap();
+        span.worst_fraction_bps = fraction_bps;
+        span.last_offence_slot = offence_slot;
 
-        // If stake falls below minimum, deactivate
-        if stake.staked_amount < MIN_VALIDATOR_STAKE {
+        // Deactivate once total backing (self + delegated) drops below minimum
+        let total_backing = stake.staked_amount.saturating_add(stake.total_delegated);
+        if total_backing < MIN_VALIDATOR_STAKE {
             stake.is_active = false;
         }
 
         msg!(
-            "Validator slashed: {} lamports (reason: {})",
-            slash_amount,
-            reason
+            "Validator slashed: {} lamports self + {} lamports delegated (reason: {}, fraction: {}bps, delta: {}bps)",
+            self_slash,
+            delegator_losses,
+            reason,
+            fraction_bps,
+            delta_bps
         );
 
         emit!(ValidatorSlashed {
             validator: stake.validator,
-            slash_amount,
+            slash_amount: total_slash,
             reason,
             evidence_root,
             remaining_stake: stake.staked_amount,
             is_deactivated: !stake.is_active,
-            slot: Clock::get()?.slot,
+            slot: current_slot,
+        });
+
+        emit!(SlashingSpanUpdated {
+            validator: stake.validator,
+            span_index: span.span_index,
+            offence_slot,
+            fraction_bps,
+            delta_bps,
+            offender_count,
+            active_validator_count,
         });
 
         Ok(())
     }
 
-    /// Award rewards to a validator for valid anchor (called internally after anchor_root)
-    pub fn award_anchor_reward(ctx: Context<AwardAnchorReward>) -> Result<()> {
+    /// Accrue era reward points for a valid anchor (a separate instruction;
+    /// `anchor_root` does not call it), weighted by `item_count` and the
+    /// validator's E-Score.
+    /// Replaces the old flat `REWARD_PER_ANCHOR` payout; lamports are
+    /// distributed proportionally to points once the era closes, via
+    /// `settle_era_reward`.
+    pub fn award_anchor_reward(ctx: Context<AwardAnchorReward>, item_count: u32) -> Result<()> {
         let stake = &mut ctx.accounts.validator_stake;
+        let era = &mut ctx.accounts.reward_era;
+        let escore = &ctx.accounts.escore_entry;
 
         require!(stake.is_active, CynicError::ValidatorNotActive);
+        require!(!era.closed, CynicError::EraClosed);
 
         stake.anchor_count += 1;
-        stake.rewards_earned = stake.rewards_earned.checked_add(REWARD_PER_ANCHOR).unwrap();
         stake.last_anchor_slot = Clock::get()?.slot;
 
-        msg!("Anchor reward awarded: {} lamports", REWARD_PER_ANCHOR);
+        let weighted_points = (item_count.max(1) as u128)
+            .checked_mul(escore_weight_bps(escore.score) as u128)
+            .unwrap()
+            .checked_div(BPS_SCALE as u128)
+            .unwrap()
+            .max(1);
+
+        stake.points = stake.points.checked_add(weighted_points).unwrap();
+        era.total_points = era.total_points.checked_add(weighted_points).unwrap();
 
-        emit!(AnchorRewardAwarded {
+        msg!(
+            "Anchor points accrued: {} (era {}, validator total {})",
+            weighted_points,
+            era.era_index,
+            stake.points
+        );
+
+        emit!(AnchorPointsAccrued {
             validator: stake.validator,
-            reward_amount: REWARD_PER_ANCHOR,
-            total_rewards: stake.rewards_earned,
+            era_index: era.era_index,
+            points_awarded: weighted_points,
+            validator_points: stake.points,
+            era_total_points: era.total_points,
             anchor_count: stake.anchor_count,
             slot: stake.last_anchor_slot,
         });
 
         Ok(())
     }
-}
 
-// ═══════════════════════════════════════════════════════════════════════════
-// Account Structures
-// ═══════════════════════════════════════════════════════════════════════════
+    /// Close the current reward era: freeze `total_points` for settlement
+    /// and fix the lamport budget to distribute from `reward_vault`. Only
+    /// callable once `REWARD_ERA_SLOTS` have elapsed since era start. Budgets
+    /// against `reward_vault.lamports() - reward_vault_commitments.outstanding`
+    /// rather than the raw balance, so this can't commit lamports
+    /// `initialize_reward_pool` already reserved for an open `RewardPool`.
+    pub fn close_reward_era(ctx: Context<CloseRewardEra>, reward_budget: u64) -> Result<()> {
+        let current_slot = Clock::get()?.slot;
 
-/// Global program state
-#[account]
-#[derive(Default)]
-pub struct CynicState {
-    /// Program authority (can manage validators)
-    pub authority: Pubkey,
-    /// Initialization timestamp
-    pub initialized_at: i64,
-    /// Total roots anchored
-    pub root_count: u64,
-    /// Number of validators
-    pub validator_count: u8,
-    /// Validator registry (max 21)
-    pub validators: [Pubkey; MAX_VALIDATORS],
-    /// Last anchor slot
-    pub last_anchor_slot: u64,
-    /// PDA bump
-    pub bump: u8,
-}
+        require!(!ctx.accounts.reward_era.closed, CynicError::EraClosed);
+        require!(
+            current_slot >= ctx.accounts.reward_era.era_start_slot + REWARD_ERA_SLOTS,
+            CynicError::EraNotYetEnded
+        );
 
-/// Individual anchored root entry
-#[account]
-#[derive(Default)]
-pub struct RootEntry {
-    /// Merkle root hash
-    pub merkle_root: [u8; 32],
-    /// Number of items in this root
-    pub item_count: u32,
-    /// PoJ block height
-    pub block_height: u64,
-    /// Validator who anchored
-    pub validator: Pubkey,
-    /// Unix timestamp
-    pub timestamp: i64,
-    /// Solana slot
-    pub slot: u64,
-    /// Sequential index
-    pub index: u64,
-}
+        // Budget against lamports neither this nor `RewardPool`'s
+        // `initialize_reward_pool` has already committed, so the two
+        // independent reward paths can't both reserve the same dollar.
+        let commitments = &mut ctx.accounts.reward_vault_commitments;
+        let available = ctx
+            .accounts
+            .reward_vault
+            .lamports()
+            .saturating_sub(commitments.outstanding);
+        require!(
+            reward_budget <= available,
+            CynicError::InsufficientRewardVault
+        );
+        commitments.outstanding = commitments.outstanding.checked_add(reward_budget).unwrap();
+        commitments.bump = ctx.bumps.reward_vault_commitments;
 
-/// Individual burn entry (BURN axiom: penalties/slashing)
-#[account]
-#[derive(Default)]
-pub struct BurnEntry {
-    /// Burned amount (in smallest unit, e.g., lamports or token decimals)
-    pub amount: u64,
-    /// Account that was burned/penalized
-    pub burned_account: Pubkey,
-    /// Reason code for the burn
-    pub reason: u8,
-    /// Associated merkle root (if burn relates to specific judgment)
-    pub merkle_root: [u8; 32],
-    /// Unix timestamp
-    pub timestamp: i64,
-    /// Solana slot
-    pub slot: u64,
-    /// Sequential burn index
-    pub index: u64,
-    /// Validator who reported the burn
-    pub reporter: Pubkey,
-}
+        let era = &mut ctx.accounts.reward_era;
+        era.closed = true;
+        era.reward_budget = reward_budget;
 
-/// Global burn statistics tracker
-#[account]
-#[derive(Default)]
-pub struct BurnTracker {
-    /// Total burns recorded
-    pub burn_count: u64,
-    /// Total amount burned across all burns
-    pub total_burned: u64,
-    /// Last burn slot
-    pub last_burn_slot: u64,
-    /// PDA bump
-    pub bump: u8,
-}
+        msg!(
+            "Reward era {} closed: {} points, {} lamport budget",
+            era.era_index,
+            era.total_points,
+            era.reward_budget
+        );
 
-/// E-Score entry (Ecosystem contribution score / reputation)
-#[account]
-#[derive(Default)]
-pub struct EScoreEntry {
-    /// Account this E-Score belongs to
-    pub account: Pubkey,
-    /// Current score (can be negative, φ-bounded)
-    pub score: i64,
-    /// Total update count
-    pub update_count: u64,
-    /// Last update slot
-    pub last_update_slot: u64,
-    /// Last judgment ID that affected this score
-    pub last_judgment_id: [u8; 32],
-    /// Count by contribution type
-    pub judgment_count: u32,
-    pub validation_count: u32,
-    pub learning_count: u32,
-    pub pattern_count: u32,
-    pub feedback_count: u32,
-    /// PDA bump
-    pub bump: u8,
-}
+        emit!(RewardEraClosed {
+            era_index: era.era_index,
+            total_points: era.total_points,
+            reward_budget: era.reward_budget,
+            slot: current_slot,
+        });
 
-/// E-Score snapshot (point-in-time historical record)
-#[account]
-#[derive(Default)]
-pub struct EScoreSnapshot {
-    /// Account this snapshot belongs to
-    pub account: Pubkey,
-    /// Score at time of snapshot
-    pub score: i64,
-    /// Associated merkle root (for verification)
-    pub merkle_root: [u8; 32],
-    /// Unix timestamp
-    pub timestamp: i64,
-    /// Solana slot
-    pub slot: u64,
-    /// Sequential snapshot index
-    pub index: u64,
-}
+        Ok(())
+    }
 
-/// E-Score snapshot tracker
-#[account]
-#[derive(Default)]
-pub struct EScoreSnapshotTracker {
-    /// Total snapshots taken
-    pub snapshot_count: u64,
-    /// Last snapshot slot
-    pub last_snapshot_slot: u64,
-    /// PDA bump
-    pub bump: u8,
-}
+    /// Settle one validator's share of a closed era's reward budget. Pays
+    /// entirely via `stake.rewards_earned`/`reward_per_share` (commission
+    /// split, same as the legacy flat-reward path); idempotent per era via
+    /// `last_settled_era_index`.
+    pub fn settle_era_reward(ctx: Context<SettleEraReward>) -> Result<()> {
+        let stake = &mut ctx.accounts.validator_stake;
+        let era = &mut ctx.accounts.reward_era;
 
-/// Validator stake entry (staking for rewards/slashing)
-#[account]
-#[derive(Default)]
-pub struct ValidatorStake {
-    /// Validator pubkey
-    pub validator: Pubkey,
-    /// Amount staked (in lamports)
-    pub staked_amount: u64,
-    /// Timestamp when staked
-    pub staked_at: i64,
+        require!(era.closed, CynicError::EraNotClosed);
+        require!(
+            stake.last_settled_era_index != era.era_index,
+            CynicError::EraAlreadySettled
+        );
+
+        let point_value = PointValue {
+            rewards: era.reward_budget,
+            points: era.total_points,
+        };
+        let reward = era_reward_for_points(point_value, stake.points)?;
+
+        let new_distributed = era
+            .rewards_distributed
+            .checked_add(reward)
+            .ok_or(CynicError::EraBudgetExceeded)?;
+        require!(
+            new_distributed <= era.reward_budget,
+            CynicError::EraBudgetExceeded
+        );
+        era.rewards_distributed = new_distributed;
+
+        // This much of the era's committed budget is now actually paid out,
+        // so it's no longer "outstanding" against `reward_vault`.
+        ctx.accounts.reward_vault_commitments.outstanding = ctx
+            .accounts
+            .reward_vault_commitments
+            .outstanding
+            .saturating_sub(reward);
+
+        let (validator_cut, delegator_pool) =
+            commission_split(reward, stake.commission_bps, stake.total_delegated);
+
+        stake.rewards_earned = stake.rewards_earned.checked_add(validator_cut).unwrap();
+        if delegator_pool > 0 && stake.total_delegated > 0 {
+            let delta = (delegator_pool as u128)
+                .checked_mul(REWARD_PER_SHARE_SCALE)
+                .unwrap()
+                .checked_div(stake.total_delegated as u128)
+                .unwrap();
+            stake.reward_per_share = stake.reward_per_share.checked_add(delta).unwrap();
+        } else {
+            stake.rewards_earned = stake.rewards_earned.checked_add(delegator_pool).unwrap();
+        }
+
+        msg!(
+            "Era {} settled for validator {}: {} lamports ({} points / {} total)",
+            era.era_index,
+            stake.validator,
+            reward,
+            stake.points,
+            era.total_points
+        );
+
+        emit!(EraRewardSettled {
+            validator: stake.validator,
+            era_index: era.era_index,
+            reward,
+            validator_points: stake.points,
+            era_total_points: era.total_points,
+            slot: Clock::get()?.slot,
+        });
+
+        stake.last_settled_era_index = era.era_index;
+        stake.points = 0;
+
+        Ok(())
+    }
+
+    /// Advance the era cursor once the current era is closed, starting a
+    /// fresh `RewardEra` account that begins accruing points immediately.
+    pub fn start_next_era(ctx: Context<StartNextEra>) -> Result<()> {
+        require!(ctx.accounts.current_era.closed, CynicError::EraNotClosed);
+
+        // `current_era` may still have validators who haven't called
+        // `settle_era_reward` yet - its lamports remain parked in
+        // `reward_vault` (never transferred out by `close_reward_era`) and
+        // stay claimable there, same as `RewardPool` leaves rounding dust in
+        // `reward_vault`. We deliberately do NOT
+        // snapshot `reward_budget - rewards_distributed` forward onto the
+        // next era: that would double-count lamports still owed to validators
+        // who settle against `current_era` after this call, crediting the
+        // same leftover to two eras at once. The off-chain caller picks each
+        // era's `reward_budget` from the vault's actual balance instead.
+        let cursor = &mut ctx.accounts.era_cursor;
+        let next_era = &mut ctx.accounts.next_era;
+
+        cursor.era_index = cursor.era_index.checked_add(1).unwrap();
+
+        next_era.era_index = cursor.era_index;
+        next_era.era_start_slot = Clock::get()?.slot;
+        next_era.total_points = 0;
+        next_era.reward_budget = 0;
+        next_era.rewards_distributed = 0;
+        next_era.closed = false;
+        next_era.bump = ctx.bumps.next_era;
+
+        msg!("Reward era {} started", next_era.era_index);
+
+        emit!(RewardEraStarted {
+            era_index: next_era.era_index,
+            start_slot: next_era.era_start_slot,
+        });
+
+        Ok(())
+    }
+
+    /// Open a vault-bounded `RewardPool` for Solana epoch `epoch`, fixing
+    /// the lamports it may distribute. Separate from the `RewardEra` system
+    /// above in points/cursor terms - no cursor, just one account per
+    /// on-chain epoch - but budgets against
+    /// `reward_vault.lamports() - reward_vault_commitments.outstanding`, the
+    /// same shared counter `close_reward_era` draws against, so this can't
+    /// commit lamports an open era already reserved.
+    pub fn initialize_reward_pool(
+        ctx: Context<InitializeRewardPool>,
+        epoch: u64,
+        epoch_reward_budget: u64,
+    ) -> Result<()> {
+        require!(
+            epoch == Clock::get()?.epoch,
+            CynicError::RewardPoolEpochMismatch
+        );
+
+        // Budget against lamports neither this nor `RewardEra`'s
+        // `close_reward_era` has already committed, so the two independent
+        // reward paths can't both reserve the same dollar.
+        let commitments = &mut ctx.accounts.reward_vault_commitments;
+        let available = ctx
+            .accounts
+            .reward_vault
+            .lamports()
+            .saturating_sub(commitments.outstanding);
+        require!(
+            epoch_reward_budget <= available,
+            CynicError::InsufficientRewardVault
+        );
+        commitments.outstanding = commitments
+            .outstanding
+            .checked_add(epoch_reward_budget)
+            .unwrap();
+        commitments.bump = ctx.bumps.reward_vault_commitments;
+
+        let pool = &mut ctx.accounts.reward_pool;
+        pool.epoch = epoch;
+        pool.epoch_reward_budget = epoch_reward_budget;
+        pool.total_points = 0;
+        pool.rewards_distributed = 0;
+        pool.bump = ctx.bumps.reward_pool;
+
+        msg!(
+            "Reward pool opened for epoch {}: {} lamport budget",
+            epoch,
+            epoch_reward_budget
+        );
+
+        emit!(RewardPoolInitialized {
+            epoch,
+            epoch_reward_budget,
+        });
+
+        Ok(())
+    }
+
+    /// Accrue `staked_amount * item_count` epoch points for a valid anchor,
+    /// feeding `DistributeEpochRewards` instead of a flat per-anchor payout.
+    /// Point accrual is independent of `award_anchor_reward`'s era-based
+    /// points - both may run per anchor without interacting - but the two
+    /// systems' lamport budgets are not: `initialize_reward_pool` and
+    /// `close_reward_era` both draw against `reward_vault` through the
+    /// shared `RewardVaultCommitments` counter so neither over-promises it.
+    pub fn accrue_epoch_points(ctx: Context<AccrueEpochPoints>, item_count: u32) -> Result<()> {
+        let stake = &mut ctx.accounts.validator_stake;
+        let pool = &mut ctx.accounts.reward_pool;
+
+        require!(stake.is_active, CynicError::ValidatorNotActive);
+        require!(
+            pool.epoch == Clock::get()?.epoch,
+            CynicError::RewardPoolEpochMismatch
+        );
+
+        let points = (stake.staked_amount as u128)
+            .checked_mul(item_count.max(1) as u128)
+            .unwrap();
+
+        stake.epoch_points = stake.epoch_points.checked_add(points).unwrap();
+        pool.total_points = pool.total_points.checked_add(points).unwrap();
+
+        msg!(
+            "Epoch points accrued: {} (epoch {}, validator total {})",
+            points,
+            pool.epoch,
+            stake.epoch_points
+        );
+
+        emit!(EpochPointsAccrued {
+            validator: stake.validator,
+            epoch: pool.epoch,
+            points_awarded: points,
+            validator_points: stake.epoch_points,
+            pool_total_points: pool.total_points,
+        });
+
+        Ok(())
+    }
+
+    /// Settle one validator's share of a past epoch's `RewardPool`, once
+    /// the on-chain epoch has rolled over (so `total_points` is final).
+    /// `reward = validator_points * epoch_reward_budget / total_points`,
+    /// u128 intermediates only, asserting `rewards_distributed <=
+    /// epoch_reward_budget` so the pool can never promise more than its
+    /// fixed budget. Any rounding remainder stays unspent in `reward_vault`
+    /// and also stays counted in `reward_vault_commitments.outstanding`
+    /// (only paid-out rewards are subtracted), so it is not carried into
+    /// the next pool's budget.
+    pub fn distribute_epoch_rewards(ctx: Context<DistributeEpochRewards>) -> Result<()> {
+        let stake = &mut ctx.accounts.validator_stake;
+        let pool = &mut ctx.accounts.reward_pool;
+
+        require!(
+            Clock::get()?.epoch > pool.epoch,
+            CynicError::RewardPoolEpochNotYetRolledOver
+        );
+        require!(
+            stake.last_distributed_epoch != pool.epoch,
+            CynicError::RewardPoolAlreadyDistributed
+        );
+
+        let reward = if pool.total_points == 0 {
+            0
+        } else {
+            (pool.epoch_reward_budget as u128)
+                .checked_mul(stake.epoch_points)
+                .ok_or(CynicError::RewardPoolBudgetExceeded)?
+                .checked_div(pool.total_points)
+                .ok_or(CynicError::RewardPoolBudgetExceeded)? as u64
+        };
+
+        let new_distributed = pool
+            .rewards_distributed
+            .checked_add(reward)
+            .ok_or(CynicError::RewardPoolBudgetExceeded)?;
+        require!(
+            new_distributed <= pool.epoch_reward_budget,
+            CynicError::RewardPoolBudgetExceeded
+        );
+        pool.rewards_distributed = new_distributed;
+
+        // This much of the pool's committed budget is now actually paid
+        // out, so it's no longer "outstanding" against `reward_vault`.
+        ctx.accounts.reward_vault_commitments.outstanding = ctx
+            .accounts
+            .reward_vault_commitments
+            .outstanding
+            .saturating_sub(reward);
+
+        stake.rewards_earned = stake.rewards_earned.checked_add(reward).unwrap();
+
+        msg!(
+            "Epoch {} rewards distributed to validator {}: {} lamports ({} points / {} total)",
+            pool.epoch,
+            stake.validator,
+            reward,
+            stake.epoch_points,
+            pool.total_points
+        );
+
+        emit!(EpochRewardsDistributed {
+            validator: stake.validator,
+            epoch: pool.epoch,
+            reward,
+            validator_points: stake.epoch_points,
+            pool_total_points: pool.total_points,
+        });
+
+        stake.last_distributed_epoch = pool.epoch;
+        stake.epoch_points = 0;
+
+        Ok(())
+    }
+
+    /// Elect the active validator set from staked candidates via sequential
+    /// Phragmén, replacing the authority-controlled whitelist and the
+    /// simpler stake+E-Score greedy ranking this instruction used before.
+    ///
+    /// `remaining_accounts` is a flat, self-describing layout: for each
+    /// candidate (in the order given by `candidate_delegator_counts`), its
+    /// `ValidatorStake` account followed by that many `Delegation` accounts
+    /// backing it. A validator's own `staked_amount` is an implicit self-vote
+    /// alongside its delegators' `amount`s.
+    ///
+    /// Each of the `k = min(MAX_VALIDATORS, candidates)` rounds elects the
+    /// not-yet-elected candidate with the minimum
+    /// `score(c) = (1 + Σ b_v·load_v) / Σ b_v` (fixed-point, scaled by
+    /// `PHRAGMEN_SCALE`; equal scores go to the lower pubkey), then sets `load_v =
+    /// score(elected)` for every voter backing it. Since each `Delegation`
+    /// backs exactly one validator (no cross-candidate approval sets), a
+    /// voter's load update can never influence a later round in this tree's
+    /// data model - but the load bookkeeping is kept so the algorithm is
+    /// correct as written and ready for a future multi-approval ballot.
+    pub fn elect_validators(
+        ctx: Context<ElectValidators>,
+        candidate_delegator_counts: Vec<u8>,
+    ) -> Result<()> {
+        require!(ctx.accounts.reward_era.closed, CynicError::EraNotClosed);
+        require!(
+            !candidate_delegator_counts.is_empty(),
+            CynicError::InvalidElectionCandidates
+        );
+
+        let remaining = ctx.remaining_accounts;
+        let mut idx = 0usize;
+        let mut voter_loads: Vec<u128> = Vec::new();
+
+        let mut candidates: Vec<ElectionCandidate> =
+            Vec::with_capacity(candidate_delegator_counts.len());
+
+        for &delegator_count in candidate_delegator_counts.iter() {
+            require!(idx < remaining.len(), CynicError::InvalidElectionCandidates);
+            let stake: Account<ValidatorStake> = Account::try_from(&remaining[idx])?;
+            idx += 1;
+
+            let self_voter_id = voter_loads.len();
+            voter_loads.push(0);
+            let mut voters = vec![self_voter_id];
+            let mut voter_budgets = vec![stake.staked_amount as u128];
+            let mut budget = stake.staked_amount as u128;
+
+            for _ in 0..delegator_count {
+                require!(idx < remaining.len(), CynicError::InvalidElectionCandidates);
+                let delegation: Account<Delegation> = Account::try_from(&remaining[idx])?;
+                idx += 1;
+
+                require_keys_eq!(
+                    delegation.validator,
+                    stake.validator,
+                    CynicError::DelegationValidatorMismatch
+                );
+
+                if !delegation.is_active {
+                    continue;
+                }
+
+                let voter_id = voter_loads.len();
+                voter_loads.push(0);
+                voters.push(voter_id);
+                voter_budgets.push(delegation.amount as u128);
+                budget = budget.checked_add(delegation.amount as u128).unwrap();
+            }
+
+            if stake.is_active && budget > 0 {
+                candidates.push(ElectionCandidate {
+                    pubkey: stake.validator,
+                    budget,
+                    voters,
+                    voter_budgets,
+                });
+            }
+        }
+
+        require!(idx == remaining.len(), CynicError::InvalidElectionCandidates);
+        require!(!candidates.is_empty(), CynicError::InvalidElectionCandidates);
+
+        let rounds = MAX_VALIDATORS.min(candidates.len());
+        let elected = run_phragmen_election(&candidates, &mut voter_loads, rounds);
+
+        let state = &mut ctx.accounts.state;
+        let mut validators = [Pubkey::default(); MAX_VALIDATORS];
+        for (i, pk) in elected.iter().enumerate() {
+            validators[i] = *pk;
+        }
+        state.validators = validators;
+        state.validator_count = elected.len() as u8;
+
+        msg!(
+            "Sequential Phragmén elected {} of {} candidates",
+            elected.len(),
+            candidates.len()
+        );
+
+        emit!(ValidatorsElected {
+            validator_count: elected.len() as u8,
+            candidate_count: candidates.len() as u8,
+            slot: Clock::get()?.slot,
+        });
+
+        Ok(())
+    }
+
+    /// Delegate (nominate) lamports toward an existing validator's bond.
+    /// Anyone may delegate; the validator's effective election/anchoring
+    /// weight becomes `staked_amount + total_delegated`.
+    pub fn delegate_stake(ctx: Context<DelegateStake>, amount: u64) -> Result<()> {
+        require!(amount > 0, CynicError::InvalidDelegationAmount);
+        require!(
+            ctx.accounts.validator_stake.is_active,
+            CynicError::ValidatorNotActive
+        );
+
+        let delegation = &mut ctx.accounts.delegation;
+        let stake = &mut ctx.accounts.validator_stake;
+        let delegator = ctx.accounts.delegator.key();
+
+        settle_delegation_rewards(delegation, stake.reward_per_share);
+
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.delegator.to_account_info(),
+                to: ctx.accounts.stake_vault.to_account_info(),
+            },
+        );
+        anchor_lang::system_program::transfer(cpi_context, amount)?;
+
+        delegation.delegator = delegator;
+        delegation.validator = stake.validator;
+        delegation.amount = delegation.amount.checked_add(amount).unwrap();
+        if delegation.delegated_at == 0 {
+            delegation.delegated_at = Clock::get()?.unix_timestamp;
+        }
+        delegation.is_active = true;
+
+        stake.total_delegated = stake.total_delegated.checked_add(amount).unwrap();
+
+        msg!(
+            "Delegated {} lamports to validator {} (total: {})",
+            amount,
+            stake.validator,
+            delegation.amount
+        );
+
+        emit!(DelegationAdded {
+            delegator,
+            validator: stake.validator,
+            amount,
+            total_delegated_by_account: delegation.amount,
+            validator_total_delegated: stake.total_delegated,
+        });
+
+        Ok(())
+    }
+
+    /// Request undelegation (starts `UNSTAKE_COOLDOWN`). Settles any
+    /// accrued rewards and removes the delegation from the validator's
+    /// effective weight immediately, mirroring `request_unstake`.
+    pub fn request_undelegate(ctx: Context<RequestUndelegate>) -> Result<()> {
+        let delegation = &mut ctx.accounts.delegation;
+        let stake = &mut ctx.accounts.validator_stake;
+
+        require!(delegation.is_active, CynicError::DelegationNotActive);
+
+        settle_delegation_rewards(delegation, stake.reward_per_share);
+
+        stake.total_delegated = stake.total_delegated.saturating_sub(delegation.amount);
+        delegation.unstake_requested_slot = Clock::get()?.slot;
+        delegation.is_active = false;
+
+        msg!("Undelegation requested, cooldown started");
+
+        emit!(UndelegationRequested {
+            delegator: delegation.delegator,
+            validator: delegation.validator,
+            amount: delegation.amount,
+            cooldown_ends_slot: delegation.unstake_requested_slot + UNSTAKE_COOLDOWN,
+        });
+
+        Ok(())
+    }
+
+    /// Withdraw a fully-cooled-down delegation's principal back to the
+    /// delegator, closing the `Delegation` account.
+    pub fn withdraw_delegation(ctx: Context<WithdrawDelegation>) -> Result<()> {
+        let delegation = &ctx.accounts.delegation;
+        let current_slot = Clock::get()?.slot;
+
+        require!(!delegation.is_active, CynicError::DelegationStillActive);
+        require!(
+            delegation.unstake_requested_slot > 0,
+            CynicError::UndelegationNotRequested
+        );
+        require!(
+            current_slot >= delegation.unstake_requested_slot + UNSTAKE_COOLDOWN,
+            CynicError::UnstakeCooldownNotComplete
+        );
+
+        let amount = delegation.amount;
+        let delegator = delegation.delegator;
+        let validator = delegation.validator;
+
+        let bump = ctx.bumps.stake_vault;
+        let seeds = &[b"stake_vault".as_ref(), &[bump]];
+        let signer = &[&seeds[..]];
+
+        let cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.stake_vault.to_account_info(),
+                to: ctx.accounts.delegator.to_account_info(),
+            },
+            signer,
+        );
+        anchor_lang::system_program::transfer(cpi_context, amount)?;
+
+        msg!("Delegation withdrawn, {} lamports returned", amount);
+
+        emit!(DelegationWithdrawn {
+            delegator,
+            validator,
+            amount,
+            slot: current_slot,
+        });
+
+        Ok(())
+    }
+
+    /// Claim accumulated delegation rewards, settling against the
+    /// validator's current `reward_per_share` first.
+    pub fn claim_delegation_rewards(ctx: Context<ClaimDelegationRewards>) -> Result<()> {
+        let delegation = &mut ctx.accounts.delegation;
+        let stake = &ctx.accounts.validator_stake;
+
+        settle_delegation_rewards(delegation, stake.reward_per_share);
+
+        let claimable = delegation
+            .rewards_earned
+            .saturating_sub(delegation.rewards_claimed);
+        require!(claimable > 0, CynicError::NoRewardsToClaim);
+
+        let bump = ctx.bumps.reward_vault;
+        let seeds = &[b"reward_vault".as_ref(), &[bump]];
+        let signer = &[&seeds[..]];
+
+        let cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.reward_vault.to_account_info(),
+                to: ctx.accounts.delegator.to_account_info(),
+            },
+            signer,
+        );
+        anchor_lang::system_program::transfer(cpi_context, claimable)?;
+
+        delegation.rewards_claimed = delegation.rewards_earned;
+
+        msg!("Delegator claimed {} lamports in rewards", claimable);
+
+        emit!(DelegationRewardsClaimed {
+            delegator: delegation.delegator,
+            validator: delegation.validator,
+            amount: claimable,
+            total_claimed: delegation.rewards_claimed,
+            slot: Clock::get()?.slot,
+        });
+
+        Ok(())
+    }
+
+    /// Create the program's single liquid-staking pool and its pool-token
+    /// mint. Mirrors SPL stake-pool's design: depositors receive a fungible
+    /// token representing a claim on `pool_vault`, so non-validators can
+    /// back the network without running a `ValidatorStake` themselves.
+    pub fn initialize_stake_pool(ctx: Context<InitializeStakePool>) -> Result<()> {
+        let pool = &mut ctx.accounts.stake_pool;
+        pool.pool_mint = ctx.accounts.pool_mint.key();
+        pool.total_lamports = 0;
+        pool.pool_token_supply = 0;
+        pool.deposit_authority_bump = ctx.bumps.pool_deposit_authority;
+        pool.withdraw_authority_bump = ctx.bumps.pool_withdraw_authority;
+        pool.bump = ctx.bumps.stake_pool;
+
+        msg!("Liquid stake pool initialized, mint {}", pool.pool_mint);
+
+        Ok(())
+    }
+
+    /// Deposit lamports into `pool_vault` and mint pool tokens in exchange,
+    /// at the current exchange rate (1:1 on the first deposit). Rewards
+    /// credited to the pool via `fund_stake_pool` raise `total_lamports`
+    /// without minting more tokens, so the rate rises for all holders.
+    pub fn deposit_stake(ctx: Context<DepositStake>, lamports: u64) -> Result<()> {
+        require!(lamports > 0, CynicError::InvalidDelegationAmount);
+
+        let pool = &mut ctx.accounts.stake_pool;
+
+        let mint_amount = if pool.pool_token_supply == 0 || pool.total_lamports == 0 {
+            lamports
+        } else {
+            (lamports as u128)
+                .checked_mul(pool.pool_token_supply as u128)
+                .unwrap()
+                .checked_div(pool.total_lamports as u128)
+                .unwrap() as u64
+        };
+
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.depositor.to_account_info(),
+                to: ctx.accounts.pool_vault.to_account_info(),
+            },
+        );
+        anchor_lang::system_program::transfer(cpi_context, lamports)?;
+
+        let bump = pool.deposit_authority_bump;
+        let seeds = &[b"pool_deposit_authority".as_ref(), &[bump]];
+        let signer = &[&seeds[..]];
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.pool_mint.to_account_info(),
+                    to: ctx.accounts.depositor_token_account.to_account_info(),
+                    authority: ctx.accounts.pool_deposit_authority.to_account_info(),
+                },
+                signer,
+            ),
+            mint_amount,
+        )?;
+
+        pool.total_lamports = pool.total_lamports.checked_add(lamports).unwrap();
+        pool.pool_token_supply = pool.pool_token_supply.checked_add(mint_amount).unwrap();
+
+        msg!(
+            "Deposited {} lamports for {} pool tokens",
+            lamports,
+            mint_amount
+        );
+
+        emit!(PoolDeposited {
+            depositor: ctx.accounts.depositor.key(),
+            lamports,
+            pool_tokens_minted: mint_amount,
+            total_lamports: pool.total_lamports,
+            pool_token_supply: pool.pool_token_supply,
+        });
+
+        Ok(())
+    }
+
+    /// Burn pool tokens and withdraw their current lamport value from
+    /// `pool_vault`.
+    pub fn withdraw_stake(ctx: Context<WithdrawStake>, pool_tokens: u64) -> Result<()> {
+        let pool = &mut ctx.accounts.stake_pool;
+
+        require!(
+            pool_tokens > 0 && pool_tokens <= pool.pool_token_supply,
+            CynicError::InvalidDelegationAmount
+        );
+
+        let lamports_out = (pool_tokens as u128)
+            .checked_mul(pool.total_lamports as u128)
+            .unwrap()
+            .checked_div(pool.pool_token_supply as u128)
+            .unwrap() as u64;
+
+        token::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.pool_mint.to_account_info(),
+                    from: ctx.accounts.depositor_token_account.to_account_info(),
+                    authority: ctx.accounts.depositor.to_account_info(),
+                },
+            ),
+            pool_tokens,
+        )?;
+
+        let bump = ctx.bumps.pool_vault;
+        let seeds = &[b"pool_vault".as_ref(), &[bump]];
+        let signer = &[&seeds[..]];
+
+        let cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.pool_vault.to_account_info(),
+                to: ctx.accounts.depositor.to_account_info(),
+            },
+            signer,
+        );
+        anchor_lang::system_program::transfer(cpi_context, lamports_out)?;
+
+        pool.total_lamports = pool.total_lamports.checked_sub(lamports_out).unwrap();
+        pool.pool_token_supply = pool.pool_token_supply.checked_sub(pool_tokens).unwrap();
+
+        msg!(
+            "Burned {} pool tokens for {} lamports",
+            pool_tokens,
+            lamports_out
+        );
+
+        emit!(PoolWithdrawn {
+            depositor: ctx.accounts.depositor.key(),
+            pool_tokens_burned: pool_tokens,
+            lamports: lamports_out,
+            total_lamports: pool.total_lamports,
+            pool_token_supply: pool.pool_token_supply,
+        });
+
+        Ok(())
+    }
+
+    /// Credit yield to the pool without minting tokens, raising the exchange
+    /// rate for existing holders. Any signer may fund the pool; the call is
+    /// not restricted to the program authority.
+    pub fn fund_stake_pool(ctx: Context<FundStakePool>, lamports: u64) -> Result<()> {
+        require!(lamports > 0, CynicError::InvalidDelegationAmount);
+
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.funder.to_account_info(),
+                to: ctx.accounts.pool_vault.to_account_info(),
+            },
+        );
+        anchor_lang::system_program::transfer(cpi_context, lamports)?;
+
+        let pool = &mut ctx.accounts.stake_pool;
+        pool.total_lamports = pool.total_lamports.checked_add(lamports).unwrap();
+
+        msg!("Funded stake pool with {} lamports", lamports);
+
+        Ok(())
+    }
+}
+
+/// Plain-data view of one `elect_validators` candidate: a validator's own
+/// stake plus its active delegators, flattened to indices into a shared
+/// `voter_loads` vector so `run_phragmen_election` doesn't need `Account`
+/// wrappers (and so it's unit-testable without a validator).
+struct ElectionCandidate {
+    pubkey: Pubkey,
+    budget: u128,
+    voters: Vec<usize>,
+    voter_budgets: Vec<u128>,
+}
+
+/// Run `rounds` of sequential Phragmén selection over `candidates`, mutating
+/// `voter_loads` in place (indexed by the `voters` each candidate lists) and
+/// returning the elected pubkeys in election order. See `elect_validators`'s
+/// doc comment for the scoring formula and tie-break rule this implements.
+fn run_phragmen_election(
+    candidates: &[ElectionCandidate],
+    voter_loads: &mut [u128],
+    rounds: usize,
+) -> Vec<Pubkey> {
+    let mut elected: Vec<Pubkey> = Vec::with_capacity(rounds);
+    let mut pending: Vec<usize> = (0..candidates.len()).collect();
+
+    for _ in 0..rounds {
+        let mut winner_at: usize = 0;
+        let mut winner_num: u128 = 0;
+        let mut winner_den: u128 = 1;
+
+        for (pi, &ci) in pending.iter().enumerate() {
+            let cand = &candidates[ci];
+            let mut weighted_load: u128 = 0;
+            for (v, &voter_id) in cand.voters.iter().enumerate() {
+                weighted_load = weighted_load
+                    .checked_add(
+                        cand.voter_budgets[v]
+                            .checked_mul(voter_loads[voter_id])
+                            .unwrap(),
+                    )
+                    .unwrap();
+            }
+            let num = PHRAGMEN_SCALE.checked_add(weighted_load).unwrap();
+            let den = cand.budget;
+
+            let is_better = pi == 0
+                || num.checked_mul(winner_den).unwrap() < winner_num.checked_mul(den).unwrap()
+                || (num.checked_mul(winner_den).unwrap() == winner_num.checked_mul(den).unwrap()
+                    && cand.pubkey < candidates[pending[winner_at]].pubkey);
+
+            if is_better {
+                winner_at = pi;
+                winner_num = num;
+                winner_den = den;
+            }
+        }
+
+        let winner_ci = pending[winner_at];
+        let winner_score = winner_num / winner_den.max(1);
+
+        for &voter_id in candidates[winner_ci].voters.iter() {
+            voter_loads[voter_id] = winner_score;
+        }
+
+        elected.push(candidates[winner_ci].pubkey);
+        pending.remove(winner_at);
+    }
+
+    elected
+}
+
+/// Split a reward between the validator's commission and the pool shared
+/// with delegators. If nobody is delegated, the validator keeps it all
+/// regardless of `commission_bps`.
+fn commission_split(reward: u64, commission_bps: u16, total_delegated: u64) -> (u64, u64) {
+    if total_delegated == 0 {
+        return (reward, 0);
+    }
+    let validator_cut = (reward as u128)
+        .checked_mul(commission_bps as u128)
+        .unwrap()
+        .checked_div(BPS_SCALE as u128)
+        .unwrap() as u64;
+    (validator_cut, reward.saturating_sub(validator_cut))
+}
+
+/// Weight (in basis points) applied to anchor points based on E-Score:
+/// baseline 10000bps (100%) at score 0, up to +100% at `ESCORE_MAX`, down to
+/// -50% at `ESCORE_MIN`. Keeps higher-reputation validators earning more
+/// points per anchor without letting a negative score zero them out.
+fn escore_weight_bps(score: i64) -> u64 {
+    if score >= 0 {
+        let bonus = (score as u128)
+            .checked_mul(BPS_SCALE as u128)
+            .unwrap()
+            .checked_div(ESCORE_MAX as u128)
+            .unwrap() as u64;
+        BPS_SCALE.saturating_add(bonus)
+    } else {
+        let penalty = ((-score) as u128)
+            .checked_mul(BPS_SCALE as u128 / 2)
+            .unwrap()
+            .checked_div((-ESCORE_MIN) as u128)
+            .unwrap() as u64;
+        BPS_SCALE.saturating_sub(penalty)
+    }
+}
+
+/// A validator's proportional share of an era's reward budget:
+/// `rewards * validator_points / points`, entirely in u128 intermediates.
+fn era_reward_for_points(value: PointValue, validator_points: u128) -> Result<u64> {
+    if value.points == 0 {
+        return Ok(0);
+    }
+    let reward = (value.rewards as u128)
+        .checked_mul(validator_points)
+        .ok_or(CynicError::EraBudgetExceeded)?
+        .checked_div(value.points)
+        .ok_or(CynicError::EraBudgetExceeded)?;
+    Ok(reward as u64)
+}
+
+/// Lamports of a vesting tranche unlocked as of `now`: 0 before the cliff,
+/// `amount` from `end_slot` on, and a linear ramp of the full `amount` in
+/// between.
+fn linear_unlocked(tranche: &VestingTranche, now: u64) -> u64 {
+    if now < tranche.cliff_slot {
+        0
+    } else if now >= tranche.end_slot {
+        tranche.amount
+    } else {
+        let elapsed = now - tranche.cliff_slot;
+        let span = tranche.end_slot - tranche.cliff_slot;
+        ((tranche.amount as u128)
+            .checked_mul(elapsed as u128)
+            .unwrap()
+            .checked_div(span as u128)
+            .unwrap()) as u64
+    }
+}
+
+/// Settle a delegation's pending rewards against the validator's current
+/// `reward_per_share`, crediting `rewards_earned` and advancing `reward_debt`.
+fn settle_delegation_rewards(delegation: &mut Delegation, reward_per_share: u128) {
+    if delegation.amount == 0 {
+        delegation.reward_debt = reward_per_share;
+        return;
+    }
+    let accrued = reward_per_share.saturating_sub(delegation.reward_debt);
+    if accrued > 0 {
+        let pending = accrued
+            .checked_mul(delegation.amount as u128)
+            .unwrap()
+            .checked_div(REWARD_PER_SHARE_SCALE)
+            .unwrap() as u64;
+        delegation.rewards_earned = delegation.rewards_earned.checked_add(pending).unwrap();
+    }
+    delegation.reward_debt = reward_per_share;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Account Structures
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Global program state
+#[account]
+#[derive(Default)]
+pub struct CynicState {
+    /// Program authority (can manage validators)
+    pub authority: Pubkey,
+    /// Initialization timestamp
+    pub initialized_at: i64,
+    /// Total roots anchored
+    pub root_count: u64,
+    /// Number of validators
+    pub validator_count: u8,
+    /// Validator registry (max 21)
+    pub validators: [Pubkey; MAX_VALIDATORS],
+    /// Last anchor slot
+    pub last_anchor_slot: u64,
+    /// Delay, in slots, between a reward tranche being created by
+    /// `claim_rewards` and that tranche clearing its cliff (see
+    /// `RewardVesting`). Set by `initialize`, adjustable via
+    /// `set_withdrawal_timelock`.
+    pub withdrawal_timelock: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// The program's single liquid-staking pool: depositors receive a fungible
+/// `pool_mint` token representing a pro-rata claim on `pool_vault`. Modeled
+/// on SPL stake-pool's `StakePool`/pool-mint design.
+#[account]
+#[derive(Default)]
+pub struct StakePool {
+    /// Mint of the fungible pool token
+    pub pool_mint: Pubkey,
+    /// Total lamports backing the pool (deposits + funded yield)
+    pub total_lamports: u64,
+    /// Total pool tokens in circulation
+    pub pool_token_supply: u64,
+    /// Bump for the PDA authorized to mint/burn `pool_mint`
+    pub deposit_authority_bump: u8,
+    /// Bump for the `pool_withdraw_authority` PDA; `withdraw_stake` signs
+    /// with `pool_vault`'s own seeds rather than with this authority
+    pub withdraw_authority_bump: u8,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Individual anchored root entry
+#[account]
+#[derive(Default)]
+pub struct RootEntry {
+    /// Merkle root hash
+    pub merkle_root: [u8; 32],
+    /// Number of items in this root
+    pub item_count: u32,
+    /// PoJ block height
+    pub block_height: u64,
+    /// Validator who anchored
+    pub validator: Pubkey,
+    /// Unix timestamp
+    pub timestamp: i64,
+    /// Solana slot
+    pub slot: u64,
+    /// Sequential index
+    pub index: u64,
+}
+
+/// Individual burn entry (BURN axiom: penalties/slashing)
+#[account]
+#[derive(Default)]
+pub struct BurnEntry {
+    /// Burned amount (in smallest unit, e.g., lamports or token decimals)
+    pub amount: u64,
+    /// Account that was burned/penalized
+    pub burned_account: Pubkey,
+    /// Reason code for the burn
+    pub reason: u8,
+    /// Associated merkle root (if burn relates to specific judgment)
+    pub merkle_root: [u8; 32],
+    /// Unix timestamp
+    pub timestamp: i64,
+    /// Solana slot
+    pub slot: u64,
+    /// Sequential burn index
+    pub index: u64,
+    /// Validator who reported the burn
+    pub reporter: Pubkey,
+}
+
+/// Global burn statistics tracker
+#[account]
+#[derive(Default)]
+pub struct BurnTracker {
+    /// Total burns recorded
+    pub burn_count: u64,
+    /// Total amount burned across all burns
+    pub total_burned: u64,
+    /// Last burn slot
+    pub last_burn_slot: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// E-Score entry (Ecosystem contribution score / reputation)
+#[account]
+#[derive(Default)]
+pub struct EScoreEntry {
+    /// Account this E-Score belongs to
+    pub account: Pubkey,
+    /// Current score (can be negative, φ-bounded)
+    pub score: i64,
+    /// Total update count
+    pub update_count: u64,
+    /// Last update slot
+    pub last_update_slot: u64,
+    /// Last judgment ID that affected this score
+    pub last_judgment_id: [u8; 32],
+    /// Count by contribution type
+    pub judgment_count: u32,
+    pub validation_count: u32,
+    pub learning_count: u32,
+    pub pattern_count: u32,
+    pub feedback_count: u32,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// E-Score snapshot (point-in-time historical record)
+#[account]
+#[derive(Default)]
+pub struct EScoreSnapshot {
+    /// Account this snapshot belongs to
+    pub account: Pubkey,
+    /// Score at time of snapshot
+    pub score: i64,
+    /// Associated merkle root (for verification)
+    pub merkle_root: [u8; 32],
+    /// Unix timestamp
+    pub timestamp: i64,
+    /// Solana slot
+    pub slot: u64,
+    /// Sequential snapshot index
+    pub index: u64,
+}
+
+/// E-Score snapshot tracker
+#[account]
+#[derive(Default)]
+pub struct EScoreSnapshotTracker {
+    /// Total snapshots taken
+    pub snapshot_count: u64,
+    /// Last snapshot slot
+    pub last_snapshot_slot: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Validator stake entry (staking for rewards/slashing)
+#[account]
+#[derive(Default)]
+pub struct ValidatorStake {
+    /// Validator pubkey
+    pub validator: Pubkey,
+    /// Amount staked (in lamports)
+    pub staked_amount: u64,
+    /// Timestamp when staked
+    pub staked_at: i64,
     /// Slot of last anchor
     pub last_anchor_slot: u64,
     /// Total anchors submitted
@@ -928,35 +2255,706 @@ pub struct ValidatorStake {
     pub total_slashed: u64,
     /// Slot when unstake was requested (0 if not requested)
     pub unstake_requested_slot: u64,
-    /// Whether validator is currently active
+    /// Whether validator is currently active (own active stake plus
+    /// `total_delegated` >= `MIN_VALIDATOR_STAKE`)
+    pub is_active: bool,
+    /// Unbonding chunks pushed by `request_unstake`, each maturing at its own
+    /// `unlock_slot`; withdrawn (and removed) by `withdraw_unbonded`
+    pub unlocking: [UnlockChunk; MAX_UNLOCKING_CHUNKS],
+    /// Number of populated entries in `unlocking`
+    pub unlocking_len: u8,
+    /// Commission the validator keeps from delegator rewards, in basis
+    /// points (e.g. 1000 = 10%)
+    pub commission_bps: u16,
+    /// Sum of lamports currently delegated to this validator by nominators
+    pub total_delegated: u64,
+    /// Cumulative delegator reward per delegated lamport, scaled by
+    /// `REWARD_PER_SHARE_SCALE`. Rises each time `settle_era_reward` splits
+    /// off the non-commission share; delegators settle against it lazily.
+    pub reward_per_share: u128,
+    /// Points accrued this reward era from valid anchors, weighted by
+    /// `item_count` and E-Score. Reset to 0 once `settle_era_reward` runs.
+    pub points: u128,
+    /// Index of the last era this validator settled a reward for, so
+    /// `settle_era_reward` can't be replayed within the same era.
+    pub last_settled_era_index: u64,
+    /// Points accrued this Solana epoch via `accrue_epoch_points`: each
+    /// anchor adds `staked_amount * item_count` (`item_count` at least 1),
+    /// so anchors are weighted by item count rather than counted once each.
+    /// Entirely separate from
+    /// `points`'s era-based accrual above - this backs `RewardPool`'s
+    /// vault-bounded, on-chain-epoch-keyed distribution instead. Reset to 0
+    /// once settled via `distribute_epoch_rewards`.
+    pub epoch_points: u128,
+    /// Epoch this validator last ran `distribute_epoch_rewards` for, so it
+    /// can't be replayed within the same epoch's `RewardPool`.
+    pub last_distributed_epoch: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// A nominator's delegation toward a specific validator's bond
+#[account]
+#[derive(Default)]
+pub struct Delegation {
+    /// The delegator (nominator) who owns this delegation
+    pub delegator: Pubkey,
+    /// The validator being backed
+    pub validator: Pubkey,
+    /// Currently delegated lamports
+    pub amount: u64,
+    /// Snapshot of `ValidatorStake.reward_per_share` at last settlement,
+    /// scaled by `REWARD_PER_SHARE_SCALE`. Used to compute newly accrued
+    /// rewards without iterating every delegation on each payout.
+    pub reward_debt: u128,
+    /// Total rewards accrued (settled but not yet claimed)
+    pub rewards_earned: u64,
+    /// Total rewards claimed so far
+    pub rewards_claimed: u64,
+    /// Timestamp of the first delegation
+    pub delegated_at: i64,
+    /// Slot when undelegation was requested (0 if not requested)
+    pub unstake_requested_slot: u64,
+    /// Whether this delegation is still backing the validator
     pub is_active: bool,
     /// PDA bump
     pub bump: u8,
 }
 
-// ═══════════════════════════════════════════════════════════════════════════
-// Contexts
-// ═══════════════════════════════════════════════════════════════════════════
+/// Tracks the current reward era index, so `RewardEra` PDAs (one per era,
+/// kept around for late settlement) can be derived deterministically
+#[account]
+#[derive(Default)]
+pub struct EraCursor {
+    /// Index of the era currently accruing points
+    pub era_index: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Shared outstanding-commitment counter for `reward_vault`, so the
+/// independent `RewardEra` (era-based, `award_anchor_reward`/
+/// `close_reward_era`/`settle_era_reward`) and `RewardPool` (epoch-based,
+/// `initialize_reward_pool`/`distribute_epoch_rewards`) reward paths can't
+/// each reserve against the same still-unspent vault balance.
+/// `close_reward_era`/`initialize_reward_pool` add to `outstanding` when
+/// fixing a budget; `settle_era_reward`/`distribute_epoch_rewards` subtract
+/// from it as they pay out, so a later budget can only draw against lamports
+/// neither path has already promised.
+#[account]
+#[derive(Default)]
+pub struct RewardVaultCommitments {
+    /// Lamports reserved from `reward_vault` for a closed era or an opened
+    /// pool that haven't been paid out yet
+    pub outstanding: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Per-era reward pool and point totals. A new account is created (not
+/// reused) for each era so validators can still settle against an era after
+/// the cursor has moved on.
+#[account]
+#[derive(Default)]
+pub struct RewardEra {
+    /// Era this account represents
+    pub era_index: u64,
+    /// Slot the era started accruing points
+    pub era_start_slot: u64,
+    /// Sum of `points` accrued by every validator this era
+    pub total_points: u128,
+    /// Lamports allocated from `reward_vault` for this era, set at close.
+    pub reward_budget: u64,
+    /// Lamports distributed so far via `settle_era_reward`
+    pub rewards_distributed: u64,
+    /// Whether the era has been closed (budget fixed, no more accrual)
+    pub closed: bool,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// `(rewards, points)` pair used to compute a validator's proportional share
+/// of an era's reward budget with u128 intermediates only - no floats.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct PointValue {
+    /// Lamports allocated for the era
+    pub rewards: u64,
+    /// Total points accrued across all validators in the era
+    pub points: u128,
+}
+
+/// Vault-bounded reward pool for a single Solana epoch, entirely separate
+/// from `RewardEra`'s internally-tracked era system: this one is keyed
+/// directly off `Clock::epoch` rather than a cursor this program advances
+/// itself. One account per epoch, created by `initialize_reward_pool` and
+/// never reused, so a validator can still call `distribute_epoch_rewards`
+/// against an old epoch after a new one has started.
+#[account]
+#[derive(Default)]
+pub struct RewardPool {
+    /// Solana epoch this pool accrues points for
+    pub epoch: u64,
+    /// Lamports this epoch may distribute in total, fixed at
+    /// `initialize_reward_pool` time
+    pub epoch_reward_budget: u64,
+    /// Sum of every validator's `epoch_points` accrued this epoch
+    pub total_points: u128,
+    /// Lamports distributed so far via `distribute_epoch_rewards`; never
+    /// exceeds `epoch_reward_budget`
+    pub rewards_distributed: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// A single unbonding chunk: `value` lamports pulled out of active stake,
+/// released from `stake_vault` once the current slot reaches `unlock_slot`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct UnlockChunk {
+    /// Lamports unbonded in this chunk
+    pub value: u64,
+    /// Slot at which this chunk may be withdrawn
+    pub unlock_slot: u64,
+}
+
+/// A single reward tranche: `amount` lamports that unlock linearly between
+/// `cliff_slot` and `end_slot`, nothing claimable before the cliff and all
+/// of it claimable from `end_slot` on
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct VestingTranche {
+    /// Lamports this tranche represents
+    pub amount: u64,
+    /// Slot the tranche was created (reward newly earned)
+    pub start_slot: u64,
+    /// Slot before which none of this tranche is claimable
+    pub cliff_slot: u64,
+    /// Slot at which this tranche is fully unlocked
+    pub end_slot: u64,
+    /// Lamports of this tranche already paid out by `claim_rewards`
+    pub claimed: u64,
+}
+
+/// Tracks the current slashing offence window for a validator, so
+/// re-reporting the same (or a lesser) offence within the window only ever
+/// applies the difference against the worst fraction already slashed.
+#[account]
+#[derive(Default)]
+pub struct SlashingSpan {
+    /// Validator this span belongs to
+    pub validator: Pubkey,
+    /// Increments each time the span is pruned and a fresh one starts
+    pub span_index: u64,
+    /// Slot the current span started (first offence slot observed in it)
+    pub start_slot: u64,
+    /// Worst slash fraction (bps) applied within this span so far
+    pub worst_fraction_bps: u64,
+    /// Slot of the last offence reported, whether or not it raised the
+    /// slash, keyed alongside `validator`
+    /// so re-reporting the exact same `(validator, slot_of_offence)` is a
+    /// guaranteed no-op regardless of the fraction it would otherwise compute
+    pub last_offence_slot: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Per-validator queue of reward tranches awaiting vesting. Each call to
+/// `claim_rewards` that finds newly-earned, not-yet-tranched rewards pushes a
+/// tranche here (bounded by `MAX_VESTING_TRANCHES`, so a validator that never
+/// claims can't grow this account without bound); the same call then pays out
+/// the requested amount, which may not exceed the portion of the queue that
+/// has linearly unlocked past its cliff.
+#[account]
+#[derive(Default)]
+pub struct RewardVesting {
+    /// Validator this vesting queue belongs to
+    pub validator: Pubkey,
+    /// Fixed-size FIFO queue of outstanding tranches
+    pub tranches: [VestingTranche; MAX_VESTING_TRANCHES],
+    /// Number of populated entries in `tranches`
+    pub tranche_len: u8,
+    /// Cumulative lamports ever tranched (used to detect newly-earned,
+    /// not-yet-tranched rewards against `ValidatorStake::rewards_earned`)
+    pub total_vested: u64,
+    /// Cumulative lamports ever paid out via `claim_rewards`
+    pub total_claimed: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Contexts
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<CynicState>(),
+        seeds = [b"cynic_state"],
+        bump
+    )]
+    pub state: Account<'info, CynicState>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ManageValidator<'info> {
+    #[account(
+        mut,
+        seeds = [b"cynic_state"],
+        bump = state.bump,
+        has_one = authority
+    )]
+    pub state: Account<'info, CynicState>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(merkle_root: [u8; 32], item_count: u32, block_height: u64)]
+pub struct AnchorRoot<'info> {
+    #[account(
+        mut,
+        seeds = [b"cynic_state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, CynicState>,
+
+    #[account(
+        init,
+        payer = validator,
+        space = 8 + std::mem::size_of::<RootEntry>(),
+        seeds = [b"root", merkle_root.as_ref()],
+        bump
+    )]
+    pub root_entry: Account<'info, RootEntry>,
+
+    #[account(mut)]
+    pub validator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(merkle_root: [u8; 32])]
+pub struct VerifyRoot<'info> {
+    #[account(
+        seeds = [b"root", merkle_root.as_ref()],
+        bump
+    )]
+    pub root_entry: Account<'info, RootEntry>,
+}
+
+#[derive(Accounts)]
+#[instruction(merkle_root: [u8; 32], item_hash: [u8; 32], proof: Vec<[u8; 32]>, proof_flags: Vec<bool>)]
+pub struct VerifyInclusion<'info> {
+    #[account(
+        seeds = [b"root", merkle_root.as_ref()],
+        bump
+    )]
+    pub root_entry: Account<'info, RootEntry>,
+}
+
+#[derive(Accounts)]
+pub struct TransferAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"cynic_state"],
+        bump = state.bump,
+        has_one = authority
+    )]
+    pub state: Account<'info, CynicState>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetWithdrawalTimelock<'info> {
+    #[account(
+        mut,
+        seeds = [b"cynic_state"],
+        bump = state.bump,
+        has_one = authority
+    )]
+    pub state: Account<'info, CynicState>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(burned_account: Pubkey, amount: u64, reason: u8, merkle_root: [u8; 32])]
+pub struct RecordBurn<'info> {
+    #[account(
+        mut,
+        seeds = [b"cynic_state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, CynicState>,
+
+    #[account(
+        init_if_needed,
+        payer = reporter,
+        space = 8 + std::mem::size_of::<BurnTracker>(),
+        seeds = [b"burn_tracker"],
+        bump
+    )]
+    pub burn_tracker: Account<'info, BurnTracker>,
+
+    #[account(
+        init,
+        payer = reporter,
+        space = 8 + std::mem::size_of::<BurnEntry>(),
+        seeds = [b"burn", burned_account.as_ref(), &burn_tracker.burn_count.to_le_bytes()],
+        bump
+    )]
+    pub burn_entry: Account<'info, BurnEntry>,
+
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct GetBurnStats<'info> {
+    #[account(
+        seeds = [b"burn_tracker"],
+        bump = burn_tracker.bump
+    )]
+    pub burn_tracker: Account<'info, BurnTracker>,
+}
+
+#[derive(Accounts)]
+#[instruction(target_account: Pubkey, delta: i64, contribution_type: u8, judgment_id: [u8; 32])]
+pub struct UpdateEScore<'info> {
+    #[account(
+        seeds = [b"cynic_state"],
+        bump = state.bump
+    )]
+    pub state: Account<'info, CynicState>,
+
+    #[account(
+        init_if_needed,
+        payer = reporter,
+        space = 8 + std::mem::size_of::<EScoreEntry>(),
+        seeds = [b"escore", target_account.as_ref()],
+        bump
+    )]
+    pub escore_entry: Account<'info, EScoreEntry>,
+
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct GetEScore<'info> {
+    #[account(
+        seeds = [b"escore", escore_entry.account.as_ref()],
+        bump = escore_entry.bump
+    )]
+    pub escore_entry: Account<'info, EScoreEntry>,
+}
+
+#[derive(Accounts)]
+#[instruction(merkle_root: [u8; 32])]
+pub struct SnapshotEScore<'info> {
+    #[account(
+        seeds = [b"escore", escore_entry.account.as_ref()],
+        bump = escore_entry.bump
+    )]
+    pub escore_entry: Account<'info, EScoreEntry>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + std::mem::size_of::<EScoreSnapshotTracker>(),
+        seeds = [b"escore_snapshot_tracker", escore_entry.account.as_ref()],
+        bump
+    )]
+    pub snapshot_tracker: Account<'info, EScoreSnapshotTracker>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + std::mem::size_of::<EScoreSnapshot>(),
+        seeds = [b"escore_snapshot", escore_entry.account.as_ref(), &snapshot_tracker.snapshot_count.to_le_bytes()],
+        bump
+    )]
+    pub escore_snapshot: Account<'info, EScoreSnapshot>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeStakePool<'info> {
+    #[account(
+        seeds = [b"cynic_state"],
+        bump = state.bump,
+        has_one = authority
+    )]
+    pub state: Account<'info, CynicState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<StakePool>(),
+        seeds = [b"stake_pool"],
+        bump
+    )]
+    pub stake_pool: Account<'info, StakePool>,
+
+    #[account(
+        init,
+        payer = authority,
+        mint::decimals = 9,
+        mint::authority = pool_deposit_authority,
+        seeds = [b"pool_mint"],
+        bump
+    )]
+    pub pool_mint: Account<'info, Mint>,
+
+    /// CHECK: PDA authorized to mint/burn `pool_mint`
+    #[account(
+        seeds = [b"pool_deposit_authority"],
+        bump
+    )]
+    pub pool_deposit_authority: AccountInfo<'info>,
+
+    /// CHECK: held for API symmetry with `pool_deposit_authority`; this
+    /// simplified design settles withdrawals directly via `pool_vault`'s
+    /// own PDA signature rather than delegating through a separate authority
+    #[account(
+        seeds = [b"pool_withdraw_authority"],
+        bump
+    )]
+    pub pool_withdraw_authority: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct DepositStake<'info> {
+    #[account(
+        mut,
+        seeds = [b"stake_pool"],
+        bump = stake_pool.bump
+    )]
+    pub stake_pool: Account<'info, StakePool>,
+
+    #[account(
+        mut,
+        seeds = [b"pool_mint"],
+        bump
+    )]
+    pub pool_mint: Account<'info, Mint>,
+
+    /// CHECK: PDA authorized to mint/burn `pool_mint`
+    #[account(
+        seeds = [b"pool_deposit_authority"],
+        bump = stake_pool.deposit_authority_bump
+    )]
+    pub pool_deposit_authority: AccountInfo<'info>,
+
+    /// CHECK: PDA vault that holds the liquid stake pool's SOL, separate
+    /// from `stake_vault` so a large deposit/withdrawal here can't drain
+    /// lamports backing a `ValidatorStake.staked_amount` or
+    /// `Delegation.amount`
+    #[account(
+        mut,
+        seeds = [b"pool_vault"],
+        bump
+    )]
+    pub pool_vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub depositor_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawStake<'info> {
+    #[account(
+        mut,
+        seeds = [b"stake_pool"],
+        bump = stake_pool.bump
+    )]
+    pub stake_pool: Account<'info, StakePool>,
+
+    #[account(
+        mut,
+        seeds = [b"pool_mint"],
+        bump
+    )]
+    pub pool_mint: Account<'info, Mint>,
+
+    /// CHECK: PDA vault that holds the liquid stake pool's SOL, separate
+    /// from `stake_vault` so a large withdrawal here can't drain lamports
+    /// backing a `ValidatorStake.staked_amount` or `Delegation.amount`
+    #[account(
+        mut,
+        seeds = [b"pool_vault"],
+        bump
+    )]
+    pub pool_vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub depositor_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FundStakePool<'info> {
+    #[account(
+        mut,
+        seeds = [b"stake_pool"],
+        bump = stake_pool.bump
+    )]
+    pub stake_pool: Account<'info, StakePool>,
+
+    /// CHECK: PDA vault that holds the liquid stake pool's SOL, separate
+    /// from `stake_vault` so funding the pool can't be conflated with
+    /// funding `reward_vault`/`stake_vault`'s other backers
+    #[account(
+        mut,
+        seeds = [b"pool_vault"],
+        bump
+    )]
+    pub pool_vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct StakeValidator<'info> {
+    #[account(
+        init_if_needed,
+        payer = staker,
+        space = 8 + std::mem::size_of::<ValidatorStake>(),
+        seeds = [b"validator_stake", staker.key().as_ref()],
+        bump
+    )]
+    pub validator_stake: Account<'info, ValidatorStake>,
+
+    /// CHECK: PDA vault that holds staked SOL
+    #[account(
+        mut,
+        seeds = [b"stake_vault"],
+        bump
+    )]
+    pub stake_vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub staker: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RequestUnstake<'info> {
+    #[account(
+        mut,
+        seeds = [b"validator_stake", validator.key().as_ref()],
+        bump = validator_stake.bump,
+        has_one = validator
+    )]
+    pub validator_stake: Account<'info, ValidatorStake>,
+
+    pub validator: Signer<'info>,
+}
 
 #[derive(Accounts)]
-pub struct Initialize<'info> {
+pub struct WithdrawUnbonded<'info> {
     #[account(
-        init,
-        payer = authority,
-        space = 8 + std::mem::size_of::<CynicState>(),
-        seeds = [b"cynic_state"],
+        mut,
+        seeds = [b"validator_stake", validator.key().as_ref()],
+        bump = validator_stake.bump,
+        has_one = validator
+    )]
+    pub validator_stake: Account<'info, ValidatorStake>,
+
+    /// CHECK: PDA vault that holds staked SOL
+    #[account(
+        mut,
+        seeds = [b"stake_vault"],
         bump
     )]
+    pub stake_vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub validator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimRewards<'info> {
+    #[account(
+        seeds = [b"cynic_state"],
+        bump = state.bump
+    )]
     pub state: Account<'info, CynicState>,
 
+    #[account(
+        mut,
+        seeds = [b"validator_stake", validator.key().as_ref()],
+        bump = validator_stake.bump,
+        has_one = validator
+    )]
+    pub validator_stake: Account<'info, ValidatorStake>,
+
+    #[account(
+        init_if_needed,
+        payer = validator,
+        space = 8 + std::mem::size_of::<RewardVesting>(),
+        seeds = [b"reward_vesting", validator.key().as_ref()],
+        bump
+    )]
+    pub reward_vesting: Account<'info, RewardVesting>,
+
+    /// CHECK: PDA vault that holds reward SOL
+    #[account(
+        mut,
+        seeds = [b"reward_vault"],
+        bump
+    )]
+    pub reward_vault: AccountInfo<'info>,
+
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub validator: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct ManageValidator<'info> {
+pub struct SlashValidator<'info> {
     #[account(
         mut,
         seeds = [b"cynic_state"],
@@ -965,27 +2963,59 @@ pub struct ManageValidator<'info> {
     )]
     pub state: Account<'info, CynicState>,
 
+    #[account(
+        mut,
+        seeds = [b"validator_stake", validator_stake.validator.as_ref()],
+        bump = validator_stake.bump
+    )]
+    pub validator_stake: Account<'info, ValidatorStake>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + std::mem::size_of::<SlashingSpan>(),
+        seeds = [b"slashing_span", validator_stake.validator.as_ref()],
+        bump
+    )]
+    pub slashing_span: Account<'info, SlashingSpan>,
+
+    #[account(mut)]
     pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-#[instruction(merkle_root: [u8; 32], item_count: u32, block_height: u64)]
-pub struct AnchorRoot<'info> {
+pub struct AwardAnchorReward<'info> {
     #[account(
         mut,
-        seeds = [b"cynic_state"],
-        bump = state.bump
+        seeds = [b"validator_stake", validator.key().as_ref()],
+        bump = validator_stake.bump,
+        has_one = validator
     )]
-    pub state: Account<'info, CynicState>,
+    pub validator_stake: Account<'info, ValidatorStake>,
 
     #[account(
-        init,
+        init_if_needed,
         payer = validator,
-        space = 8 + std::mem::size_of::<RootEntry>(),
-        seeds = [b"root", merkle_root.as_ref()],
+        space = 8 + std::mem::size_of::<EScoreEntry>(),
+        seeds = [b"escore", validator.key().as_ref()],
         bump
     )]
-    pub root_entry: Account<'info, RootEntry>,
+    pub escore_entry: Account<'info, EScoreEntry>,
+
+    #[account(
+        seeds = [b"era_cursor"],
+        bump = era_cursor.bump
+    )]
+    pub era_cursor: Account<'info, EraCursor>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_era", era_cursor.era_index.to_le_bytes().as_ref()],
+        bump = reward_era.bump
+    )]
+    pub reward_era: Account<'info, RewardEra>,
 
     #[account(mut)]
     pub validator: Signer<'info>,
@@ -994,160 +3024,265 @@ pub struct AnchorRoot<'info> {
 }
 
 #[derive(Accounts)]
-#[instruction(merkle_root: [u8; 32])]
-pub struct VerifyRoot<'info> {
+pub struct InitializeRewardEra<'info> {
     #[account(
-        seeds = [b"root", merkle_root.as_ref()],
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<EraCursor>(),
+        seeds = [b"era_cursor"],
         bump
     )]
-    pub root_entry: Account<'info, RootEntry>,
-}
+    pub era_cursor: Account<'info, EraCursor>,
 
-#[derive(Accounts)]
-#[instruction(merkle_root: [u8; 32], item_hash: [u8; 32], proof: Vec<[u8; 32]>, proof_flags: Vec<bool>)]
-pub struct VerifyInclusion<'info> {
     #[account(
-        seeds = [b"root", merkle_root.as_ref()],
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<RewardEra>(),
+        seeds = [b"reward_era", 1u64.to_le_bytes().as_ref()],
         bump
     )]
-    pub root_entry: Account<'info, RootEntry>,
+    pub reward_era: Account<'info, RewardEra>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct TransferAuthority<'info> {
+pub struct CloseRewardEra<'info> {
     #[account(
-        mut,
         seeds = [b"cynic_state"],
         bump = state.bump,
         has_one = authority
     )]
     pub state: Account<'info, CynicState>,
 
+    #[account(
+        seeds = [b"era_cursor"],
+        bump = era_cursor.bump
+    )]
+    pub era_cursor: Account<'info, EraCursor>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_era", era_cursor.era_index.to_le_bytes().as_ref()],
+        bump = reward_era.bump
+    )]
+    pub reward_era: Account<'info, RewardEra>,
+
+    /// CHECK: PDA vault that holds reward SOL
+    #[account(
+        seeds = [b"reward_vault"],
+        bump
+    )]
+    pub reward_vault: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + std::mem::size_of::<RewardVaultCommitments>(),
+        seeds = [b"reward_vault_commitments"],
+        bump
+    )]
+    pub reward_vault_commitments: Account<'info, RewardVaultCommitments>,
+
+    #[account(mut)]
     pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-#[instruction(burned_account: Pubkey, amount: u64, reason: u8, merkle_root: [u8; 32])]
-pub struct RecordBurn<'info> {
+pub struct SettleEraReward<'info> {
     #[account(
         mut,
+        seeds = [b"validator_stake", validator_stake.validator.as_ref()],
+        bump = validator_stake.bump
+    )]
+    pub validator_stake: Account<'info, ValidatorStake>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_era", reward_era.era_index.to_le_bytes().as_ref()],
+        bump = reward_era.bump
+    )]
+    pub reward_era: Account<'info, RewardEra>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_vault_commitments"],
+        bump = reward_vault_commitments.bump
+    )]
+    pub reward_vault_commitments: Account<'info, RewardVaultCommitments>,
+}
+
+#[derive(Accounts)]
+pub struct StartNextEra<'info> {
+    #[account(
         seeds = [b"cynic_state"],
-        bump = state.bump
+        bump = state.bump,
+        has_one = authority
     )]
     pub state: Account<'info, CynicState>,
 
     #[account(
-        init_if_needed,
-        payer = reporter,
-        space = 8 + std::mem::size_of::<BurnTracker>(),
-        seeds = [b"burn_tracker"],
-        bump
+        mut,
+        seeds = [b"era_cursor"],
+        bump = era_cursor.bump
     )]
-    pub burn_tracker: Account<'info, BurnTracker>,
+    pub era_cursor: Account<'info, EraCursor>,
+
+    #[account(
+        seeds = [b"reward_era", era_cursor.era_index.to_le_bytes().as_ref()],
+        bump = current_era.bump
+    )]
+    pub current_era: Account<'info, RewardEra>,
 
     #[account(
         init,
-        payer = reporter,
-        space = 8 + std::mem::size_of::<BurnEntry>(),
-        seeds = [b"burn", burned_account.as_ref(), &burn_tracker.burn_count.to_le_bytes()],
+        payer = authority,
+        space = 8 + std::mem::size_of::<RewardEra>(),
+        seeds = [b"reward_era", (era_cursor.era_index + 1).to_le_bytes().as_ref()],
         bump
     )]
-    pub burn_entry: Account<'info, BurnEntry>,
+    pub next_era: Account<'info, RewardEra>,
 
     #[account(mut)]
-    pub reporter: Signer<'info>,
+    pub authority: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct GetBurnStats<'info> {
+#[instruction(epoch: u64)]
+pub struct InitializeRewardPool<'info> {
     #[account(
-        seeds = [b"burn_tracker"],
-        bump = burn_tracker.bump
+        seeds = [b"cynic_state"],
+        bump = state.bump,
+        has_one = authority
     )]
-    pub burn_tracker: Account<'info, BurnTracker>,
-}
+    pub state: Account<'info, CynicState>,
 
-#[derive(Accounts)]
-#[instruction(target_account: Pubkey, delta: i64, contribution_type: u8, judgment_id: [u8; 32])]
-pub struct UpdateEScore<'info> {
     #[account(
-        seeds = [b"cynic_state"],
-        bump = state.bump
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<RewardPool>(),
+        seeds = [b"reward_pool", epoch.to_le_bytes().as_ref()],
+        bump
     )]
-    pub state: Account<'info, CynicState>,
+    pub reward_pool: Account<'info, RewardPool>,
+
+    /// CHECK: PDA vault that holds reward SOL
+    #[account(
+        seeds = [b"reward_vault"],
+        bump
+    )]
+    pub reward_vault: AccountInfo<'info>,
 
     #[account(
         init_if_needed,
-        payer = reporter,
-        space = 8 + std::mem::size_of::<EScoreEntry>(),
-        seeds = [b"escore", target_account.as_ref()],
+        payer = authority,
+        space = 8 + std::mem::size_of::<RewardVaultCommitments>(),
+        seeds = [b"reward_vault_commitments"],
         bump
     )]
-    pub escore_entry: Account<'info, EScoreEntry>,
+    pub reward_vault_commitments: Account<'info, RewardVaultCommitments>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AccrueEpochPoints<'info> {
+    #[account(
+        mut,
+        seeds = [b"validator_stake", validator.key().as_ref()],
+        bump = validator_stake.bump,
+        has_one = validator
+    )]
+    pub validator_stake: Account<'info, ValidatorStake>,
 
-    #[account(mut)]
-    pub reporter: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"reward_pool", reward_pool.epoch.to_le_bytes().as_ref()],
+        bump = reward_pool.bump
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
 
-    pub system_program: Program<'info, System>,
+    pub validator: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct GetEScore<'info> {
+pub struct DistributeEpochRewards<'info> {
     #[account(
-        seeds = [b"escore", escore_entry.account.as_ref()],
-        bump = escore_entry.bump
+        mut,
+        seeds = [b"validator_stake", validator_stake.validator.as_ref()],
+        bump = validator_stake.bump
     )]
-    pub escore_entry: Account<'info, EScoreEntry>,
-}
+    pub validator_stake: Account<'info, ValidatorStake>,
 
-#[derive(Accounts)]
-#[instruction(merkle_root: [u8; 32])]
-pub struct SnapshotEScore<'info> {
     #[account(
-        seeds = [b"escore", escore_entry.account.as_ref()],
-        bump = escore_entry.bump
+        mut,
+        seeds = [b"reward_pool", reward_pool.epoch.to_le_bytes().as_ref()],
+        bump = reward_pool.bump
     )]
-    pub escore_entry: Account<'info, EScoreEntry>,
+    pub reward_pool: Account<'info, RewardPool>,
 
     #[account(
-        init_if_needed,
-        payer = payer,
-        space = 8 + std::mem::size_of::<EScoreSnapshotTracker>(),
-        seeds = [b"escore_snapshot_tracker", escore_entry.account.as_ref()],
-        bump
+        mut,
+        seeds = [b"reward_vault_commitments"],
+        bump = reward_vault_commitments.bump
     )]
-    pub snapshot_tracker: Account<'info, EScoreSnapshotTracker>,
+    pub reward_vault_commitments: Account<'info, RewardVaultCommitments>,
+}
 
+#[derive(Accounts)]
+pub struct ElectValidators<'info> {
     #[account(
-        init,
-        payer = payer,
-        space = 8 + std::mem::size_of::<EScoreSnapshot>(),
-        seeds = [b"escore_snapshot", escore_entry.account.as_ref(), &snapshot_tracker.snapshot_count.to_le_bytes()],
-        bump
+        mut,
+        seeds = [b"cynic_state"],
+        bump = state.bump
     )]
-    pub escore_snapshot: Account<'info, EScoreSnapshot>,
+    pub state: Account<'info, CynicState>,
 
-    #[account(mut)]
-    pub payer: Signer<'info>,
+    #[account(
+        seeds = [b"era_cursor"],
+        bump = era_cursor.bump
+    )]
+    pub era_cursor: Account<'info, EraCursor>,
 
-    pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [b"reward_era", era_cursor.era_index.to_le_bytes().as_ref()],
+        bump = reward_era.bump
+    )]
+    pub reward_era: Account<'info, RewardEra>,
+    // Candidates and their delegators are supplied via `remaining_accounts`;
+    // see `elect_validators`'s doc comment for the exact layout.
 }
 
 #[derive(Accounts)]
 #[instruction(amount: u64)]
-pub struct StakeValidator<'info> {
+pub struct DelegateStake<'info> {
+    #[account(
+        mut,
+        seeds = [b"validator_stake", validator_stake.validator.as_ref()],
+        bump = validator_stake.bump
+    )]
+    pub validator_stake: Account<'info, ValidatorStake>,
+
     #[account(
         init_if_needed,
-        payer = staker,
-        space = 8 + std::mem::size_of::<ValidatorStake>(),
-        seeds = [b"validator_stake", staker.key().as_ref()],
+        payer = delegator,
+        space = 8 + std::mem::size_of::<Delegation>(),
+        seeds = [b"delegation", delegator.key().as_ref(), validator_stake.validator.as_ref()],
         bump
     )]
-    pub validator_stake: Account<'info, ValidatorStake>,
+    pub delegation: Account<'info, Delegation>,
 
-    /// CHECK: PDA vault that holds staked SOL
+    /// CHECK: PDA vault that holds staked and delegated SOL
     #[account(
         mut,
         seeds = [b"stake_vault"],
@@ -1156,35 +3291,43 @@ pub struct StakeValidator<'info> {
     pub stake_vault: AccountInfo<'info>,
 
     #[account(mut)]
-    pub staker: Signer<'info>,
+    pub delegator: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct RequestUnstake<'info> {
+pub struct RequestUndelegate<'info> {
     #[account(
         mut,
-        seeds = [b"validator_stake", validator.key().as_ref()],
-        bump = validator_stake.bump,
-        has_one = validator
+        seeds = [b"validator_stake", validator_stake.validator.as_ref()],
+        bump = validator_stake.bump
     )]
     pub validator_stake: Account<'info, ValidatorStake>,
 
-    pub validator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"delegation", delegator.key().as_ref(), validator_stake.validator.as_ref()],
+        bump = delegation.bump,
+        has_one = delegator
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    pub delegator: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct CompleteUnstake<'info> {
+pub struct WithdrawDelegation<'info> {
     #[account(
         mut,
-        seeds = [b"validator_stake", validator.key().as_ref()],
-        bump = validator_stake.bump,
-        close = validator
+        seeds = [b"delegation", delegator.key().as_ref(), delegation.validator.as_ref()],
+        bump = delegation.bump,
+        has_one = delegator,
+        close = delegator
     )]
-    pub validator_stake: Account<'info, ValidatorStake>,
+    pub delegation: Account<'info, Delegation>,
 
-    /// CHECK: PDA vault that holds staked SOL
+    /// CHECK: PDA vault that holds staked and delegated SOL
     #[account(
         mut,
         seeds = [b"stake_vault"],
@@ -1193,21 +3336,27 @@ pub struct CompleteUnstake<'info> {
     pub stake_vault: AccountInfo<'info>,
 
     #[account(mut)]
-    pub validator: Signer<'info>,
+    pub delegator: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct ClaimRewards<'info> {
+pub struct ClaimDelegationRewards<'info> {
     #[account(
-        mut,
-        seeds = [b"validator_stake", validator.key().as_ref()],
-        bump = validator_stake.bump,
-        has_one = validator
+        seeds = [b"validator_stake", validator_stake.validator.as_ref()],
+        bump = validator_stake.bump
     )]
     pub validator_stake: Account<'info, ValidatorStake>,
 
+    #[account(
+        mut,
+        seeds = [b"delegation", delegator.key().as_ref(), validator_stake.validator.as_ref()],
+        bump = delegation.bump,
+        has_one = delegator
+    )]
+    pub delegation: Account<'info, Delegation>,
+
     /// CHECK: PDA vault that holds reward SOL
     #[account(
         mut,
@@ -1217,44 +3366,11 @@ pub struct ClaimRewards<'info> {
     pub reward_vault: AccountInfo<'info>,
 
     #[account(mut)]
-    pub validator: Signer<'info>,
+    pub delegator: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
-#[derive(Accounts)]
-pub struct SlashValidator<'info> {
-    #[account(
-        mut,
-        seeds = [b"cynic_state"],
-        bump = state.bump,
-        has_one = authority
-    )]
-    pub state: Account<'info, CynicState>,
-
-    #[account(
-        mut,
-        seeds = [b"validator_stake", validator_stake.validator.as_ref()],
-        bump = validator_stake.bump
-    )]
-    pub validator_stake: Account<'info, ValidatorStake>,
-
-    pub authority: Signer<'info>,
-}
-
-#[derive(Accounts)]
-pub struct AwardAnchorReward<'info> {
-    #[account(
-        mut,
-        seeds = [b"validator_stake", validator.key().as_ref()],
-        bump = validator_stake.bump,
-        has_one = validator
-    )]
-    pub validator_stake: Account<'info, ValidatorStake>,
-
-    pub validator: Signer<'info>,
-}
-
 // ═══════════════════════════════════════════════════════════════════════════
 // Events
 // ═══════════════════════════════════════════════════════════════════════════
@@ -1304,6 +3420,11 @@ pub struct AuthorityTransferred {
     pub new_authority: Pubkey,
 }
 
+#[event]
+pub struct WithdrawalTimelockUpdated {
+    pub withdrawal_timelock: u64,
+}
+
 #[event]
 pub struct BurnRecorded {
     pub index: u64,
@@ -1364,14 +3485,18 @@ pub struct ValidatorStaked {
 #[event]
 pub struct UnstakeRequested {
     pub validator: Pubkey,
+    pub amount: u64,
     pub staked_amount: u64,
     pub cooldown_ends_slot: u64,
 }
 
 #[event]
-pub struct UnstakeCompleted {
+pub struct UnbondedWithdrawn {
     pub validator: Pubkey,
     pub amount: u64,
+    pub remaining_staked: u64,
+    pub remaining_unlocking: u8,
+    pub closed: bool,
     pub slot: u64,
 }
 
@@ -1383,6 +3508,22 @@ pub struct RewardsClaimed {
     pub slot: u64,
 }
 
+#[event]
+pub struct RewardVested {
+    pub validator: Pubkey,
+    pub amount: u64,
+    pub cliff_slot: u64,
+    pub end_slot: u64,
+}
+
+#[event]
+pub struct RewardUnlocked {
+    pub validator: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+    pub slot: u64,
+}
+
 #[event]
 pub struct ValidatorSlashed {
     pub validator: Pubkey,
@@ -1394,6 +3535,28 @@ pub struct ValidatorSlashed {
     pub slot: u64,
 }
 
+/// Emitted once per affected account (the validator itself, then each
+/// slashed delegator) so off-chain indexers can attribute the cascaded loss
+#[event]
+pub struct SlashApplied {
+    pub validator: Pubkey,
+    pub account: Pubkey,
+    pub amount: u64,
+    pub remaining: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct SlashingSpanUpdated {
+    pub validator: Pubkey,
+    pub span_index: u64,
+    pub offence_slot: u64,
+    pub fraction_bps: u64,
+    pub delta_bps: u64,
+    pub offender_count: u32,
+    pub active_validator_count: u32,
+}
+
 #[event]
 pub struct AnchorRewardAwarded {
     pub validator: Pubkey,
@@ -1403,6 +3566,124 @@ pub struct AnchorRewardAwarded {
     pub slot: u64,
 }
 
+#[event]
+pub struct AnchorPointsAccrued {
+    pub validator: Pubkey,
+    pub era_index: u64,
+    pub points_awarded: u128,
+    pub validator_points: u128,
+    pub era_total_points: u128,
+    pub anchor_count: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct RewardEraClosed {
+    pub era_index: u64,
+    pub total_points: u128,
+    pub reward_budget: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct EraRewardSettled {
+    pub validator: Pubkey,
+    pub era_index: u64,
+    pub reward: u64,
+    pub validator_points: u128,
+    pub era_total_points: u128,
+    pub slot: u64,
+}
+
+#[event]
+pub struct RewardEraStarted {
+    pub era_index: u64,
+    pub start_slot: u64,
+}
+
+#[event]
+pub struct RewardPoolInitialized {
+    pub epoch: u64,
+    pub epoch_reward_budget: u64,
+}
+
+#[event]
+pub struct EpochPointsAccrued {
+    pub validator: Pubkey,
+    pub epoch: u64,
+    pub points_awarded: u128,
+    pub validator_points: u128,
+    pub pool_total_points: u128,
+}
+
+#[event]
+pub struct EpochRewardsDistributed {
+    pub validator: Pubkey,
+    pub epoch: u64,
+    pub reward: u64,
+    pub validator_points: u128,
+    pub pool_total_points: u128,
+}
+
+#[event]
+pub struct ValidatorsElected {
+    pub validator_count: u8,
+    pub candidate_count: u8,
+    pub slot: u64,
+}
+
+#[event]
+pub struct DelegationAdded {
+    pub delegator: Pubkey,
+    pub validator: Pubkey,
+    pub amount: u64,
+    pub total_delegated_by_account: u64,
+    pub validator_total_delegated: u64,
+}
+
+#[event]
+pub struct UndelegationRequested {
+    pub delegator: Pubkey,
+    pub validator: Pubkey,
+    pub amount: u64,
+    pub cooldown_ends_slot: u64,
+}
+
+#[event]
+pub struct DelegationWithdrawn {
+    pub delegator: Pubkey,
+    pub validator: Pubkey,
+    pub amount: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct DelegationRewardsClaimed {
+    pub delegator: Pubkey,
+    pub validator: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct PoolDeposited {
+    pub depositor: Pubkey,
+    pub lamports: u64,
+    pub pool_tokens_minted: u64,
+    pub total_lamports: u64,
+    pub pool_token_supply: u64,
+}
+
+#[event]
+pub struct PoolWithdrawn {
+    pub depositor: Pubkey,
+    pub pool_tokens_burned: u64,
+    pub lamports: u64,
+    pub total_lamports: u64,
+    pub pool_token_supply: u64,
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Errors
 // ═══════════════════════════════════════════════════════════════════════════
@@ -1459,4 +3740,189 @@ pub enum CynicError {
 
     #[msg("No rewards available to claim")]
     NoRewardsToClaim,
+
+    #[msg("Commission must be at most 10000 basis points (100%)")]
+    InvalidCommission,
+
+    #[msg("Delegation amount must be greater than zero")]
+    InvalidDelegationAmount,
+
+    #[msg("Delegation is not active")]
+    DelegationNotActive,
+
+    #[msg("Delegation is still active - request undelegation first")]
+    DelegationStillActive,
+
+    #[msg("Undelegation has not been requested")]
+    UndelegationNotRequested,
+
+    #[msg("Reward era is already closed")]
+    EraClosed,
+
+    #[msg("Reward era has not yet ended")]
+    EraNotYetEnded,
+
+    #[msg("Reward vault does not hold enough lamports for this budget")]
+    InsufficientRewardVault,
+
+    #[msg("Reward era is not closed yet")]
+    EraNotClosed,
+
+    #[msg("Validator already settled this era's reward")]
+    EraAlreadySettled,
+
+    #[msg("Era reward distribution would exceed the allocated budget")]
+    EraBudgetExceeded,
+
+    #[msg("Reward pool epoch does not match the current on-chain epoch")]
+    RewardPoolEpochMismatch,
+
+    #[msg("Reward pool's epoch has not yet rolled over")]
+    RewardPoolEpochNotYetRolledOver,
+
+    #[msg("Validator already distributed this epoch's reward pool")]
+    RewardPoolAlreadyDistributed,
+
+    #[msg("Reward pool distribution would exceed the allocated budget")]
+    RewardPoolBudgetExceeded,
+
+    #[msg("Offender count must be > 0 and <= active validator count")]
+    InvalidOffenderCount,
+
+    #[msg("Election candidates must be a non-empty, exactly-consumed remaining_accounts list of [ValidatorStake, Delegation...] per candidate")]
+    InvalidElectionCandidates,
+
+    #[msg("E-Score account does not match the candidate's validator stake account")]
+    EScoreAccountMismatch,
+
+    #[msg("Unstake amount must be > 0 and <= active staked amount")]
+    InvalidUnstakeAmount,
+
+    #[msg("Too many pending unlocking chunks (max 13)")]
+    TooManyUnlockingChunks,
+
+    #[msg("Delegation account does not back the candidate's validator stake account")]
+    DelegationValidatorMismatch,
+
+    #[msg("Claim amount must be > 0 and <= the currently vested/unlocked portion")]
+    RewardsLocked,
+}
+
+#[cfg(test)]
+mod phragmen_tests {
+    use super::*;
+
+    /// A candidate with a single self-voter at `budget` stake and no
+    /// delegators, voting into its own slot in `voter_loads`.
+    fn solo_candidate(pubkey: Pubkey, voter_id: usize, budget: u128) -> ElectionCandidate {
+        ElectionCandidate {
+            pubkey,
+            budget,
+            voters: vec![voter_id],
+            voter_budgets: vec![budget],
+        }
+    }
+
+    fn pubkey(byte: u8) -> Pubkey {
+        Pubkey::new_from_array([byte; 32])
+    }
+
+    #[test]
+    fn test_tie_is_broken_by_lower_pubkey() {
+        // Equal budgets and no prior load: every candidate's score ties at
+        // round 1, so the winner must be the lowest pubkey, not list order.
+        let a = pubkey(3);
+        let b = pubkey(1);
+        let c = pubkey(2);
+        let candidates = vec![
+            solo_candidate(a, 0, 100),
+            solo_candidate(b, 1, 100),
+            solo_candidate(c, 2, 100),
+        ];
+        let mut voter_loads = vec![0u128; 3];
+
+        let elected = run_phragmen_election(&candidates, &mut voter_loads, 1);
+
+        assert_eq!(elected, vec![b]);
+    }
+
+    #[test]
+    fn test_elects_all_equal_candidates_in_pubkey_order() {
+        // Three equal-budget, non-overlapping candidates: no voter backs
+        // more than one candidate, so every round is a tie at
+        // PHRAGMEN_SCALE / 100, broken by the lowest remaining pubkey.
+        let a = pubkey(1);
+        let b = pubkey(2);
+        let c = pubkey(3);
+        let candidates = vec![
+            solo_candidate(a, 0, 100),
+            solo_candidate(b, 1, 100),
+            solo_candidate(c, 2, 100),
+        ];
+        let mut voter_loads = vec![0u128; 3];
+
+        let elected = run_phragmen_election(&candidates, &mut voter_loads, 3);
+
+        assert_eq!(elected.len(), 3);
+        assert_eq!(elected, vec![a, b, c]);
+        // Each elected candidate's lone voter load was set to that round's
+        // winning score, the same PHRAGMEN_SCALE / 100 every round since no
+        // voter's load feeds into another candidate's score.
+        assert_eq!(voter_loads[0], PHRAGMEN_SCALE / 100);
+        assert_eq!(voter_loads[1], PHRAGMEN_SCALE / 100);
+        assert_eq!(voter_loads[2], PHRAGMEN_SCALE / 100);
+    }
+
+    #[test]
+    fn test_heavier_stake_wins_before_lighter_stake() {
+        // Candidate with the larger budget has a lower score
+        // (PHRAGMEN_SCALE / budget) and must be elected first.
+        let heavy = pubkey(1);
+        let light = pubkey(2);
+        let candidates = vec![
+            solo_candidate(heavy, 0, 1_000),
+            solo_candidate(light, 1, 10),
+        ];
+        let mut voter_loads = vec![0u128; 2];
+
+        let elected = run_phragmen_election(&candidates, &mut voter_loads, 1);
+
+        assert_eq!(elected, vec![heavy]);
+    }
+
+    #[test]
+    fn test_shared_voter_load_carries_into_next_round() {
+        // "shared" backs both "a" and "b". "b" has the larger backing (150
+        // vs 100), so it scores lower and is elected first; that raises
+        // shared's load, which must then count against "a" in the next
+        // round - "a"'s score should reflect shared's updated load, not 0.
+        let a = pubkey(1);
+        let b = pubkey(2);
+        let candidates = vec![
+            ElectionCandidate {
+                pubkey: a,
+                budget: 100,
+                voters: vec![0],
+                voter_budgets: vec![100],
+            },
+            ElectionCandidate {
+                pubkey: b,
+                budget: 150,
+                voters: vec![0, 1],
+                voter_budgets: vec![100, 50],
+            },
+        ];
+        let mut voter_loads = vec![0u128; 2];
+
+        let elected = run_phragmen_election(&candidates, &mut voter_loads, 2);
+
+        assert_eq!(elected, vec![b, a]);
+        // Round 2's winner is "a" (the only remaining candidate), scored
+        // against shared's load as left by round 1 (b's winning score).
+        // Shared ends at a's score; b's other voter keeps b's score.
+        let b_score = PHRAGMEN_SCALE / 150;
+        let a_score = (PHRAGMEN_SCALE + 100 * b_score) / 100;
+        assert_eq!(voter_loads[0], a_score);
+        assert_eq!(voter_loads[1], b_score);
+    }
 }